@@ -2,90 +2,339 @@
 ///
 /// For readability, the expected syntax of the macro is the following:
 /// ```text
+/// ( #[cfg(Predicate)] | #[Attribute] )*
 /// ( [ Generics ] )?
-/// impl Trait, Method for LHS(, RHS)?
+/// impl ( const )? Trait, Method for LHS(, RHS)?
 /// ( where Bounds )?
 /// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Attribute`s are any other attributes, which are put on every generated method, e.g. `#[inline]` or `#[cfg_attr(feature = "fast", inline(always))]`
 /// - `Generics` are comma-seperated type or const generics
+/// - `const` makes the generated implementation `impl const`, see [Const implementations](crate#const-implementations)
 /// - `Trait` is the trait to be implemented
 /// - `Method` is the method that `Trait` defines\
-///   (can be ommitted for [`AddAssign`](https://doc.rust-lang.org/std/ops/trait.AddAssign.html), [`SubAssign`](https://doc.rust-lang.org/std/ops/trait.SubAssign.html), [`MulAssign`](https://doc.rust-lang.org/std/ops/trait.MulAssign.html) and [`DivAssign`](https://doc.rust-lang.org/std/ops/trait.DivAssign.html))
+///   (can be ommitted for all assignment operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
 /// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
 /// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
 ///   if no `RHS` is given, `LHS` = `RHS` is assumed
 /// - `Bounds` are comma-seperated trait bounds for the listed generics
 #[macro_export]
 macro_rules! forward_ref_op_assign {
+    // remember whether the implementations are const while processing the rest of the input
     (
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl AddAssign for $lhs:ty $(, $rhs:ty )?
-        $( where $($bound:tt)* )?
+        impl const $($rest:tt)*
     ) => {
-        forward_ref_op_assign! {
+        $crate::forward_ref_op_assign! {
+            @front [const]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl AddAssign, add_assign for $lhs $(, $rhs )?
-            $( where $($bound)* )?
+            impl $($rest)*
+        }
+    };
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $($rest:tt)*
+    ) => {
+        $crate::forward_ref_op_assign! {
+            @front []
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $($rest)*
         }
     };
+
     (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl SubAssign for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident for $lhs:ty $(, $rhs:ty )?
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_op_assign! {
+        $crate::__forward_ref_method! {
+            assign forward_ref_op_assign { @front [ $($constness)? ] $( #[ $($attr)* ] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs $(, $rhs )? $( where $($bound)* )? }
+        }
+    };
+
+    // if no RHS was given, assume RHS = LHS
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_op_assign! {
+            @front [ $($constness)? ]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl SubAssign, sub_assign for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $lhs
             $( where $($bound)* )?
         }
     };
+
+    // sorts the attributes into those for the implementation and those for the method
     (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl MulAssign for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_op_assign! {
+        $crate::__forward_ref_attrs! {
+            forward_ref_op_assign { @emit [ $($constness)? ] }
+            [] []
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl MulAssign, mul_assign for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
+
+    // `constness` is `const` if the implementation was given as `impl const`
+    (
+        @emit [ $($constness:tt)? ]
+        [ $( #[cfg $cfg:tt] )* ] [ $( #[ $($attr:tt)* ] )* ]
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $($constness)? $impl<&$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            $( #[ $($attr)* ] )*
+            fn $meth(&mut self, rhs: &$rhs) {
+                <$lhs>::$meth(self, *rhs)
+            }
+        }
+    };
+
+    // friendlier errors for common mistakes
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl < $($rest:tt)*
+    ) => {
+        compile_error!("generics have to be listed in brackets in front of `impl`, e.g. `[T] impl AddAssign for Point<T>`");
+    };
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty $(, $rhs:ty )? ; $($rest:tt)*
+    ) => {
+        compile_error!("unexpected `;`, the types have to be followed by `where` or the end of the input");
+    };
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty $(, $rhs:ty )? { $($body:tt)* } $($rest:tt)*
+    ) => {
+        compile_error!("unexpected implementation body, `forward_ref_op_assign` generates the implementations itself");
+    };
+    ($($input:tt)*) => {
+        compile_error!("expected input of the form `( [ Generics ] )? impl ( const )? Trait(, Method)? for LHS(, RHS)? ( where Bounds )?`");
+    };
+}
+
+/// For types `T`, `U: Copy` for which [`AddAssign`](https://doc.rust-lang.org/std/ops/trait.AddAssign.html), [`SubAssign`](https://doc.rust-lang.org/std/ops/trait.SubAssign.html), [`MulAssign`](https://doc.rust-lang.org/std/ops/trait.MulAssign.html) and [`DivAssign`](https://doc.rust-lang.org/std/ops/trait.DivAssign.html) are implemented (`T += U`, `T -= U`, `T *= U` and `T /= U`), invoke [`forward_ref_op_assign`] for all four of them.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl for LHS(, RHS)?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `LHS` is the type of the left hand side of the operations (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operations (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+#[macro_export]
+macro_rules! forward_ref_arith_assign {
+    // if no RHS was given, assume RHS = LHS
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl DivAssign for $lhs:ty $(, $rhs:ty )?
+        impl for $lhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_op_assign! {
+        $crate::forward_ref_arith_assign! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
-            impl DivAssign, div_assign for $lhs $(, $rhs )?
+            impl for $lhs, $lhs
             $( where $($bound)* )?
         }
     };
 
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        // `forward_ref_op_assign` names the traits as given, so they have to be in scope
+        const _: () = {
+            use ::core::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+            $crate::forward_ref_op_assign! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl AddAssign for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_op_assign! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl SubAssign for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_op_assign! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl MulAssign for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_op_assign! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl DivAssign for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+        };
+    };
+}
+
+/// For types `T: Copy`, `U` for which shift operator `shift` is implemented with output `T` (`T shift U`), implement `T shift= U` as `*self = *self shift rhs`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait for LHS(, RHS)?
+/// ( ; forward_ref )?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented, i.e. either `ShlAssign` or `ShrAssign`
+/// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `forward_ref` additionally implements `T shift= &U` using [`forward_ref_op_assign`]\
+///   this requires `U: Copy`
+/// - `Bounds` are comma-seperated trait bounds for the listed generics\
+///   they need to imply `T: Shl<U, Output = T>` or `T: Shr<U, Output = T>` respectively
+#[macro_export]
+macro_rules! derive_shift_assign_from_shift {
     // if no RHS was given, assume RHS = LHS
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl $impl:ident, $meth:ident for $lhs:ty
+        impl $impl:ident for $lhs:ty
+        $( ; $option:ident )?
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_op_assign! {
+        $crate::derive_shift_assign_from_shift! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
-            impl $impl, $meth for $lhs, $lhs
+            impl $impl for $lhs, $lhs
+            $( ; $option )?
             $( where $($bound)* )?
         }
     };
 
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        impl ShlAssign for $lhs:ty, $rhs:ty
+        $( ; $option:ident )?
         $( where $($bound:tt)* )?
     ) => {
-        impl$(<$($generic)*>)? $impl<&$rhs> for $lhs
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::ops::ShlAssign<$rhs> for $lhs
         $(where
             $($bound)*)?
         {
-            fn $meth(&mut self, rhs: &$rhs) {
-                <$lhs>::$meth(self, *rhs)
+            fn shl_assign(&mut self, rhs: $rhs) {
+                *self = *self << rhs;
             }
         }
+
+        $crate::derive_shift_assign_from_shift! {
+            @option [ $($option)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl ShlAssign for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl ShrAssign for $lhs:ty, $rhs:ty
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::ops::ShrAssign<$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            fn shr_assign(&mut self, rhs: $rhs) {
+                *self = *self >> rhs;
+            }
+        }
+
+        $crate::derive_shift_assign_from_shift! {
+            @option [ $($option)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl ShrAssign for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        @option []
+        $($rest:tt)*
+    ) => {};
+    (
+        @option [ forward_ref ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        // `forward_ref_op_assign` names the trait as given, so it has to be in scope
+        const _: () = {
+            use ::core::ops::$impl;
+
+            $crate::forward_ref_op_assign! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl $impl for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+        };
+    };
+    (
+        @option [ $other:ident ]
+        $($rest:tt)*
+    ) => {
+        compile_error!(concat!("unknown option `", stringify!($other), "`, expected `forward_ref`"));
     };
 }
@@ -1,51 +1,58 @@
 /// For types `T`, `U` for which binary operator `binop` is implemented (`T binop U`), also implement `U binop T`.
-/// This macro will fail if `LHS` = `RHS`.
+/// This macro will fail if `LHS` = `RHS`, unless `allow_same` is given.
 ///
 /// For readability, the expected syntax of the macro is the following:
 /// ```text
+/// ( #[cfg(Predicate)] )*
 /// ( [ Generics ] )?
 /// impl Trait, Method for LHS(, RHS)?
+/// ( ; allow_same )?
 /// ( where Bounds )?
 /// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
 /// - `Generics` are comma-seperated type or const generics
 /// - `Trait` is the trait to be implemented
 /// - `Method` is the method that `Trait` defines\
-///   (can be ommitted for [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html) and [`Mul`](https://doc.rust-lang.org/std/ops/trait.Mul.html))
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
 /// - `LHS` is the type of the left hand side of the original operation (i.e. `T`)
 /// - `RHS` is the type of the right hand side of the original operation (i.e. `U`)
+/// - `allow_same` skips implementing `U binop T`, declaring that `LHS` = `RHS`
 /// - `Bounds` are comma-seperated trait bounds for the listed generics
 ///
 /// Note in particular that `LHS` and `RHS` denote the left and right side of the **original** operation, not the one being created. The reason for this is to be consistent with all other macros in this crate, even if it seems unintuitive.
+///
+/// If `LHS` = `RHS`, `U binop T` is the original operation itself and implementing it again results in conflicting implementations.
+/// Macros cannot compare types, so this case cannot be detected automatically.
+/// When invoking the macro from generated code where `LHS` and `RHS` may be the same type, `allow_same` can be given explicitly for that case, in which case the macro expands to nothing.
 #[macro_export]
 macro_rules! commutative_binop {
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl Add for $lhs:ty, $rhs:ty
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty, $rhs:ty; allow_same
         $( where $($bound:tt)* )?
-    ) => {
-        commutative_binop! {
-            $( [ $($generic)* ] )?
-            impl Add, add for $lhs, $rhs
-            $( where $($bound)* )?
-        }
-    };
+    ) => {};
+
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl Mul for $lhs:ty, $rhs:ty
+        impl $impl:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        commutative_binop! {
-            $( [ $($generic)* ] )?
-            impl Mul, mul for $lhs, $rhs
-            $( where $($bound)* )?
+        $crate::__forward_ref_method! {
+            binary commutative_binop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs, $rhs $( where $($bound)* )? }
         }
     };
 
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
         impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
+        $( #[cfg $cfg] )*
         impl$(<$($generic)*>)? $impl<$lhs> for $rhs
         $(where
             $($bound)*)?
@@ -63,83 +70,458 @@ macro_rules! commutative_binop {
 ///
 /// For readability, the expected syntax of the macro is the following:
 /// ```text
-/// ( [ Generics ] )?
-/// impl Trait, Method for LHS(, RHS)?
+/// ( #[cfg(Predicate)] | #[Attribute] )*
+/// ( [ Generics ] ( [ RHS Generics ] )? )?
+/// impl ( const )? Trait, Method for LHS(, RHS)?
+/// ( ; depth(Depth) )?
 /// ( where Bounds )?
 /// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Attribute`s are any other attributes, which are put on every generated method, e.g. `#[inline]` or `#[cfg_attr(feature = "fast", inline(always))]`
 /// - `Generics` are comma-seperated type or const generics
+/// - `RHS Generics` are comma-seperated type or const generics that are only used by `RHS`\
+///   they are merged with `Generics`, leaving out those whose name is already listed in `Generics`, so generics used by both `LHS` and `RHS` may be listed in both\
+///   lifetimes have to be listed in `Generics`, as they have to come before all type and const generics
+/// - `const` makes the generated implementations `impl const`, see [Const implementations](crate#const-implementations)
 /// - `Trait` is the trait to be implemented
 /// - `Method` is the method that `Trait` defines\
-///   (can be ommitted for [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html), [`Sub`](https://doc.rust-lang.org/std/ops/trait.Sub.html), [`Mul`](https://doc.rust-lang.org/std/ops/trait.Mul.html) and [`Div`](https://doc.rust-lang.org/std/ops/trait.Div.html))
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
 /// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
 /// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
 ///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `Depth` is either `1` (the default) or `2`\
+///   with `depth(2)`, `&&T binop &&U` is implemented as well, e.g. for operands from iterating over a slice of references
 /// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// The operator traits in [`std::ops`](https://doc.rust-lang.org/std/ops) already declare their methods `#[must_use]`, so the generated variants are linted just like the original operation if their result is unused:
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use std::ops::Add;
+/// use forward_ref_generic::forward_ref_binop;
+///
+/// #[derive(Clone, Copy)]
+/// struct Int(i32);
+///
+/// impl Add for Int {
+///     type Output = Self;
+///
+///     fn add(self, rhs: Self) -> Self::Output {
+///         Self(self.0 + rhs.0)
+///     }
+/// }
+///
+/// forward_ref_binop! {
+///     impl Add for Int
+/// }
+///
+/// let (a, b) = (Int(1), Int(2));
+/// // error: unused arithmetic operation that must be used
+/// &a + b;
+/// ```
 #[macro_export]
 macro_rules! forward_ref_binop {
+    // remember where the implementations go and whether they are const while processing the rest of the input
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )*
+        impl const $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop! {
+            @front [$crate::__forward_ref_identity] [const]
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )*
+            impl $($rest)*
+        }
+    };
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )*
+        impl $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop! {
+            @front [$crate::__forward_ref_identity] []
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )*
+            impl $($rest)*
+        }
+    };
+
+    // merge the generics of LHS and RHS, leaving out those of RHS that are already listed for LHS,
+    // `forward_ref_binop_debug` is used in expression position, every other invocation in item position
+    (
+        @front [stringify] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        [ $($generic:tt)* ] [ $($rhs_generic:tt)* ]
+        impl $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_union! {
+            expr forward_ref_binop { @front [stringify] [ $($constness)? ] $( #[ $($attr)* ] )* }
+            [ $($generic)* ] [ $($rhs_generic)* ]
+            { impl $($rest)* }
+        }
+    };
     (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        [ $($generic:tt)* ] [ $($rhs_generic:tt)* ]
+        impl $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_union! {
+            item forward_ref_binop { @front [ $($sink)* ] [ $($constness)? ] $( #[ $($attr)* ] )* }
+            [ $($generic)* ] [ $($rhs_generic)* ]
+            { impl $($rest)* }
+        }
+    };
+
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty $(, $rhs:ty )?
+        $( ; depth($depth:tt) )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary forward_ref_binop { @front [ $($sink)* ] [ $($constness)? ] $( #[ $($attr)* ] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs $(, $rhs )? $( ; depth($depth) )? $( where $($bound)* )? }
+        }
+    };
+
+    // if no RHS was given, assume RHS = LHS
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty
+        $( ; depth($depth:tt) )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_binop! {
+            @front [ $($sink)* ] [ $($constness)? ]
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $lhs
+            $( ; depth($depth) )?
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl Add for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        ; depth(1)
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_binop! {
+        $crate::forward_ref_binop! {
+            @front [ $($sink)* ] [ $($constness)? ]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl Add, add for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
     (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl Sub for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        ; depth(2)
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_binop! {
+        $crate::forward_ref_binop! {
+            @emit [ $($sink)* ] [ $($constness)? ] [2]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl Sub, sub for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
+
     (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl Mul for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_binop! {
+        $crate::forward_ref_binop! {
+            @emit [ $($sink)* ] [ $($constness)? ] [1]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl Mul, mul for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
+
+    // sorts the attributes into those for the implementations and those for the methods
+    (
+        @emit [ $($sink:tt)* ] [ $($constness:tt)? ] [ $depth:tt ]
+        $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_attrs! {
+            forward_ref_binop { @emit_sorted [ $($sink)* ] [ $($constness)? ] [ $depth ] }
+            [] []
+            $($rest)*
+        }
+    };
+
+    // `&&T binop &&U` for `depth(2)`, handed to `sink` together with the other implementations
     (
+        @emit_sorted [ $($sink:tt)* ] [ $($constness:tt)? ] [2]
+        [ $( #[cfg $cfg:tt] )* ] [ $( #[ $($attr:tt)* ] )* ]
         $( [ $($generic:tt)* ] )?
-        impl Div for $lhs:ty $(, $rhs:ty )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_binop! {
+        $crate::forward_ref_binop! {
+            @emit_sorted [ $($sink)* ] [ $($constness)? ] [1]
+            {
+                $( #[cfg $cfg] )*
+                impl$(<$($generic)*>)? $($constness)? $impl<&&$rhs> for &&$lhs
+                $(where
+                    $($bound)*)?
+                {
+                    type Output = <$lhs as $impl<$rhs>>::Output;
+
+                    $( #[ $($attr)* ] )*
+                    fn $meth(self, rhs: &&$rhs) -> Self::Output {
+                        <$lhs>::$meth(**self, **rhs)
+                    }
+                }
+            }
+            [ $( #[cfg $cfg] )* ] [ $( #[ $($attr)* ] )* ]
             $( [ $($generic)* ] )?
-            impl Div, div for $lhs $(, $rhs )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
 
+    // hands the generated implementations to `sink`,
+    // which is either an identity macro or `stringify` for `forward_ref_binop_debug`,
+    // `constness` is `const` if the implementations were given as `impl const`
+    (
+        @emit_sorted [ $($sink:tt)* ] [ $($constness:tt)? ] [1]
+        $( { $($depth2:tt)* } )?
+        [ $( #[cfg $cfg:tt] )* ] [ $( #[ $($attr:tt)* ] )* ]
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $($sink)*! {
+            $( #[cfg $cfg] )*
+            impl$(<$($generic)*>)? $($constness)? $impl<$rhs> for &$lhs
+            $(where
+                $($bound)*)?
+            {
+                type Output = <$lhs as $impl<$rhs>>::Output;
+
+                $( #[ $($attr)* ] )*
+                fn $meth(self, rhs: $rhs) -> Self::Output {
+                    <$lhs>::$meth(*self, rhs)
+                }
+            }
+
+            $( #[cfg $cfg] )*
+            impl$(<$($generic)*>)? $($constness)? $impl<&$rhs> for $lhs
+            $(where
+                $($bound)*)?
+            {
+                type Output = <$lhs as $impl<$rhs>>::Output;
+
+                $( #[ $($attr)* ] )*
+                fn $meth(self, rhs: &$rhs) -> Self::Output {
+                    <$lhs>::$meth(self, *rhs)
+                }
+            }
+
+            $( #[cfg $cfg] )*
+            impl$(<$($generic)*>)? $($constness)? $impl<&$rhs> for &$lhs
+            $(where
+                $($bound)*)?
+            {
+                type Output = <$lhs as $impl<$rhs>>::Output;
+
+                $( #[ $($attr)* ] )*
+                fn $meth(self, rhs: &$rhs) -> Self::Output {
+                    <$lhs>::$meth(*self, *rhs)
+                }
+            }
+
+            $( $($depth2)* )?
+        }
+    };
+
+    // friendlier errors for common mistakes
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl < $($rest:tt)*
+    ) => {
+        compile_error!("generics have to be listed in brackets in front of `impl`, e.g. `[T] impl Add for Point<T>`");
+    };
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty $(, $rhs:ty )? ; depth($depth:tt) $($rest:tt)*
+    ) => {
+        compile_error!(concat!("unsupported `depth(", stringify!($depth), ")`, expected `depth(1)` or `depth(2)`"));
+    };
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty $(, $rhs:ty )? ; $($rest:tt)*
+    ) => {
+        compile_error!("unexpected `;`, the types have to be followed by `where` or the end of the input");
+    };
+    (
+        @front [ $($sink:tt)* ] [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $lhs:ty $(, $rhs:ty )? { $($body:tt)* } $($rest:tt)*
+    ) => {
+        compile_error!("unexpected implementation body, `forward_ref_binop` generates the implementations itself");
+    };
+    ($($input:tt)*) => {
+        compile_error!("expected input of the form `( [ Generics ] )? impl ( const )? Trait(, Method)? for LHS(, RHS)? ( ; depth(Depth) )? ( where Bounds )?`");
+    };
+}
+
+/// Returns the code [`forward_ref_binop`] generates for the same input as a `&'static str`.
+///
+/// This is meant as a quick way to inspect the generated implementations without needing `cargo expand`, e.g. when a combination of generics and bounds fails to compile.
+/// The syntax is the same as [`forward_ref_binop`]'s, but the macro is used in expression position and does not implement anything.
+///
+/// ```
+/// use forward_ref_generic::forward_ref_binop_debug;
+///
+/// let expansion = forward_ref_binop_debug! {
+///     [T]
+///     impl Add for Point<T>
+///     where T: Copy + Add<Output = T>
+/// };
+/// println!("{}", expansion);
+/// ```
+#[macro_export]
+macro_rules! forward_ref_binop_debug {
+    // goes through the same arms as `forward_ref_binop`, but hands the implementations to `stringify`
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )*
+        impl const $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop! {
+            @front [stringify] [const]
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )*
+            impl $($rest)*
+        }
+    };
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )*
+        impl $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop! {
+            @front [stringify] []
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )*
+            impl $($rest)*
+        }
+    };
+    // let `forward_ref_binop` report the error
+    ($($input:tt)*) => {
+        $crate::forward_ref_binop! { $($input)* }
+    };
+}
+
+/// For types `T: Clone`, `U: Clone` for which binary operator `binop` is implemented (`T binop U`), also implement `T binop &U`, `&T binop U` and `&T binop &U`.
+///
+/// Unlike [`forward_ref_binop`], the implementations clone the operands instead of copying them.
+/// Only the borrowed operands are cloned, i.e. `T binop &U` clones just the right hand side, `&T binop U` clones just the left hand side and `&T binop &U` clones both.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait, Method for LHS(, RHS)?
+/// ( ; unsized_rhs )?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `unsized_rhs` is for a `RHS` that may be unsized (`U: ?Sized`, e.g. `str`)\
+///   an unsized `U` cannot be passed by value, so neither `T binop U` nor `&T binop U` can exist\
+///   instead, `T binop &U` has to be implemented already and only `&T binop &U` is implemented by cloning the left hand side
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+#[macro_export]
+macro_rules! forward_ref_binop_clone {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty $(, $rhs:ty )?
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary forward_ref_binop_clone { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs $(, $rhs )? $( ; $option )? $( where $($bound)* )? }
+        }
+    };
+
     // if no RHS was given, assume RHS = LHS
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
         impl $impl:ident, $meth:ident for $lhs:ty
+        $( ; $option:ident )?
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_binop! {
+        $crate::forward_ref_binop_clone! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
             impl $impl, $meth for $lhs, $lhs
+            $( ; $option )?
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_binop_clone! {
+            @option [ $($option)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
     };
 
     (
+        @option []
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
         impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
+        $( #[cfg $cfg] )*
         impl$(<$($generic)*>)? $impl<$rhs> for &$lhs
         $(where
             $($bound)*)?
@@ -147,10 +529,11 @@ macro_rules! forward_ref_binop {
             type Output = <$lhs as $impl<$rhs>>::Output;
 
             fn $meth(self, rhs: $rhs) -> Self::Output {
-                <$lhs>::$meth(*self, rhs)
+                <$lhs>::$meth(::core::clone::Clone::clone(self), rhs)
             }
         }
 
+        $( #[cfg $cfg] )*
         impl$(<$($generic)*>)? $impl<&$rhs> for $lhs
         $(where
             $($bound)*)?
@@ -158,16 +541,148 @@ macro_rules! forward_ref_binop {
             type Output = <$lhs as $impl<$rhs>>::Output;
 
             fn $meth(self, rhs: &$rhs) -> Self::Output {
-                <$lhs>::$meth(self, *rhs)
+                <$lhs>::$meth(self, ::core::clone::Clone::clone(rhs))
             }
         }
 
+        $( #[cfg $cfg] )*
         impl$(<$($generic)*>)? $impl<&$rhs> for &$lhs
         $(where
             $($bound)*)?
         {
             type Output = <$lhs as $impl<$rhs>>::Output;
 
+            fn $meth(self, rhs: &$rhs) -> Self::Output {
+                <$lhs>::$meth(
+                    ::core::clone::Clone::clone(self),
+                    ::core::clone::Clone::clone(rhs),
+                )
+            }
+        }
+    };
+    (
+        @option [ unsized_rhs ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        // the output of `T binop &U` may depend on the lifetime of `&U`, so it needs a name
+        $( #[cfg $cfg] )*
+        impl<'rhs, $($($generic)*)?> $impl<&'rhs $rhs> for &$lhs
+        $(where
+            $($bound)*)?
+        {
+            type Output = <$lhs as $impl<&'rhs $rhs>>::Output;
+
+            fn $meth(self, rhs: &'rhs $rhs) -> Self::Output {
+                <$lhs>::$meth(::core::clone::Clone::clone(self), rhs)
+            }
+        }
+    };
+    (
+        @option [ $other:ident ]
+        $($rest:tt)*
+    ) => {
+        compile_error!(concat!("unknown option `", stringify!($other), "`, expected `unsized_rhs`"));
+    };
+}
+
+/// For types `T: Copy`, `U: Copy` for which binary operator `binop` is implemented (`T binop U`), only implement `&T binop &U`.
+///
+/// This is meant for operands that are always borrowed, where [`forward_ref_binop`]'s `T binop &U` and `&T binop U` are not wanted or would conflict with other implementations.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl ( const )? Trait, Method for LHS(, RHS)?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `const` makes the generated implementation `impl const`, see [Const implementations](crate#const-implementations)
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+#[macro_export]
+macro_rules! forward_ref_binop_refs {
+    // remember whether the implementation is const while processing the rest of the input
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl const $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop_refs! {
+            @front [const]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $($rest)*
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $($rest:tt)*
+    ) => {
+        $crate::forward_ref_binop_refs! {
+            @front []
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $($rest)*
+        }
+    };
+
+    (
+        @front [ $($constness:tt)? ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty $(, $rhs:ty )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary forward_ref_binop_refs { @front [ $($constness)? ] $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs $(, $rhs )? $( where $($bound)* )? }
+        }
+    };
+
+    // if no RHS was given, assume RHS = LHS
+    (
+        @front [ $($constness:tt)? ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_binop_refs! {
+            @front [ $($constness)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $lhs
+            $( where $($bound)* )?
+        }
+    };
+
+    // `constness` is `const` if the implementation was given as `impl const`
+    (
+        @front [ $($constness:tt)? ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $($constness)? $impl<&$rhs> for &$lhs
+        $(where
+            $($bound)*)?
+        {
+            type Output = <$lhs as $impl<$rhs>>::Output;
+
             fn $meth(self, rhs: &$rhs) -> Self::Output {
                 <$lhs>::$meth(*self, *rhs)
             }
@@ -180,54 +695,858 @@ macro_rules! forward_ref_binop {
 ///
 /// For readability, the expected syntax of the macro is the following:
 /// ```text
+/// ( #[cfg(Predicate)] )*
 /// ( [ Generics ] )?
 /// impl Trait, Method for LHS(, RHS)?
 /// ( where Bounds )?
 /// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
 /// - `Generics` are comma-seperated type or const generics
 /// - `Trait` is the trait to be implemented
 /// - `Method` is the method that `Trait` defines\
-///   (can be ommitted for [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html) and [`Mul`](https://doc.rust-lang.org/std/ops/trait.Mul.html))
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
 /// - `LHS` is the type of the left hand side of the original operation (i.e. `T`)
 /// - `RHS` is the type of the right hand side of the original operation (i.e. `U`)
 /// - `Bounds` are comma-seperated trait bounds for the listed generics
 #[macro_export]
 macro_rules! forward_ref_commutative_binop {
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl Add for $lhs:ty, $rhs:ty
+        impl $impl:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_commutative_binop! {
-            $( [ $($generic)* ] )?
-            impl Add, add for $lhs, $rhs
-            $( where $($bound)* )?
+        $crate::__forward_ref_method! {
+            binary forward_ref_commutative_binop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs, $rhs $( where $($bound)* )? }
         }
     };
+
     (
+        $( #[cfg $cfg:tt] )*
         $( [ $($generic:tt)* ] )?
-        impl Mul for $lhs:ty, $rhs:ty
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_commutative_binop! {
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
-            impl Mul, mul for $lhs, $rhs
+            impl $impl, $meth for $rhs, $lhs
             $( where $($bound)* )?
         }
     };
+}
 
-    (
-        $( [ $($generic:tt)* ] )?
-        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
-        $( where $($bound:tt)* )?
-    ) => {
-        forward_ref_generic::forward_ref_binop! {
+/// For types `T`, `U` for which `T + (-U)` is implemented, implement `T - U` as `T + (-U)`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Sub for LHS(, RHS)?
+/// ( ; forward_ref )?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `LHS` is the type of the left hand side of the operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operation (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `forward_ref` additionally implements `T - &U`, `&T - U` and `&T - &U` using [`forward_ref_binop`]\
+///   this requires `T: Copy` and `U: Copy`
+/// - `Bounds` are comma-seperated trait bounds for the listed generics\
+///   they need to imply `U: Neg` and `T: Add<<U as Neg>::Output>`
+#[macro_export]
+macro_rules! derive_sub_from_add_neg {
+    // if no RHS was given, assume RHS = LHS
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Sub for $lhs:ty
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::derive_sub_from_add_neg! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl Sub for $lhs, $lhs
+            $( ; $option )?
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Sub for $lhs:ty, $rhs:ty
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::ops::Sub<$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            type Output = <$lhs as ::core::ops::Add<<$rhs as ::core::ops::Neg>::Output>>::Output;
+
+            fn sub(self, rhs: $rhs) -> Self::Output {
+                self + (-rhs)
+            }
+        }
+
+        $crate::derive_sub_from_add_neg! {
+            @option [ $($option)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl Sub for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        @option []
+        $($rest:tt)*
+    ) => {};
+    (
+        @option [ forward_ref ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Sub for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        // `forward_ref_binop` names the trait as given, so it has to be in scope
+        const _: () = {
+            use ::core::ops::Sub;
+
+            $crate::forward_ref_binop! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Sub, sub for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+        };
+    };
+    (
+        @option [ $other:ident ]
+        $($rest:tt)*
+    ) => {
+        compile_error!(concat!("unknown option `", stringify!($other), "`, expected `forward_ref`"));
+    };
+}
+
+/// For a type `T: Copy` and scalar types `S: Copy` for which binary operator `binop` is implemented (`T binop S`), also implement `S binop T` for every listed scalar, as well as all variants with references of both.
+///
+/// Due to Rust's [orphan rules](https://doc.rust-lang.org/reference/items/implementations.html#orphan-rules), `S binop T` can only be implemented in the crate that defines `T` or `S`.
+/// As `T` is the first local type in `impl binop<T> for S`, this works for primitive scalars like `f64` as well as crate-local scalar types.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait, Method for Type, scalars [ Scalar, ... ]
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `Type` is the type the operation is implemented on (i.e. `T`)
+/// - `Scalar`s are the types of the scalars (i.e. `S`)
+/// - `Bounds` are comma-seperated trait bounds for the listed generics\
+///   `Type: Trait<Scalar>` is added automatically for each `Scalar`
+#[macro_export]
+macro_rules! commutative_scalar_mul {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $type:ty, scalars [ $($scalar:ty),+ ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary commutative_scalar_mul { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $type, scalars [ $($scalar),+ ] $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty, scalars [ $scalar:ty $(, $rest:ty )* ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $impl<$type> for $scalar
+        where
+            $type: $impl<$scalar>,
+            $($($bound)*)?
+        {
+            type Output = <$type as $impl<$scalar>>::Output;
+
+            fn $meth(self, rhs: $type) -> Self::Output {
+                <$type>::$meth(rhs, self)
+            }
+        }
+
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type, $scalar
+            where $type: $impl<$scalar>, $($($bound)*)?
+        }
+
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $scalar, $type
+            where $type: $impl<$scalar>, $($($bound)*)?
+        }
+
+        $crate::commutative_scalar_mul! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type, scalars [ $($rest),* ]
+            $( where $($bound)* )?
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty, scalars []
+        $( where $($bound:tt)* )?
+    ) => {};
+}
+
+/// For a type `T: Copy` for which shift operator `shift` is implemented with integer shift amounts `I` (`T shift I`), also implement `T shift &I`, `&T shift I` and `&T shift &I` for every such `I`.
+///
+/// The standard library implements [`Shl`](https://doc.rust-lang.org/std/ops/trait.Shl.html) and [`Shr`](https://doc.rust-lang.org/std/ops/trait.Shr.html) on integers for every integer type as the shift amount.
+/// If no `Amount`s are given, this macro does the same, i.e. it assumes `T shift I` is implemented for `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `i8`, `i16`, `i32`, `i64`, `i128` and `isize`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? for Type(, amounts [ Amount, ... ])?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented\
+///   if `Method` is ommitted for `Shl` or `Shr`, both `Shl` and `Shr` are implemented, as the standard library always implements both
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for `Shl` and `Shr`, see [Custom operators](crate#custom-operators))
+/// - `Type` is the type the operation is implemented on (i.e. `T`)
+/// - `Amount`s are the types of the shift amounts (i.e. `I`)
+/// - `Bounds` are comma-seperated trait bounds for the listed generics\
+///   `Type: Trait<Amount>` is added automatically for each `Amount`
+///
+/// # Example
+///
+/// ```
+/// use std::ops::{Shl, Shr};
+/// use forward_ref_generic::forward_ref_shift;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Bits(u64);
+///
+/// impl Shl<u32> for Bits {
+///     type Output = Self;
+///
+///     fn shl(self, rhs: u32) -> Self::Output {
+///         Self(self.0 << rhs)
+///     }
+/// }
+///
+/// impl Shr<u32> for Bits {
+///     type Output = Self;
+///
+///     fn shr(self, rhs: u32) -> Self::Output {
+///         Self(self.0 >> rhs)
+///     }
+/// }
+///
+/// // implements the variants of both `Shl` and `Shr`
+/// forward_ref_shift! {
+///     impl Shl for Bits, amounts [u32]
+/// }
+///
+/// assert_eq!(&Bits(1) << &3u32, Bits(8));
+/// assert_eq!(&Bits(8) >> &3u32, Bits(1));
+/// ```
+#[macro_export]
+macro_rules! forward_ref_shift {
+    // if no amounts were given, use every integer type like std does
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $type:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_shift! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl $(, $meth )? for $type,
+            amounts [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+            $( where $($bound)* )?
+        }
+    };
+
+    // `Shl` and `Shr` without a method forward both traits
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Shl for $type:ty, amounts [ $($amount:ty),+ ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_shift! {
+            @both
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl for $type, amounts [ $($amount),+ ]
+            $( where $($bound)* )?
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Shr for $type:ty, amounts [ $($amount:ty),+ ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_shift! {
+            @both
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl for $type, amounts [ $($amount),+ ]
+            $( where $($bound)* )?
+        }
+    };
+    (
+        @both
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl for $type:ty, amounts [ $($amount:ty),+ ]
+        $( where $($bound:tt)* )?
+    ) => {
+        // `forward_ref_binop` names the traits as given, so they have to be in scope
+        const _: () = {
+            use ::core::ops::{Shl, Shr};
+
+            $crate::forward_ref_shift! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Shl, shl for $type, amounts [ $($amount),+ ]
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_shift! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Shr, shr for $type, amounts [ $($amount),+ ]
+                $( where $($bound)* )?
+            }
+        };
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $type:ty, amounts [ $($amount:ty),+ ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary forward_ref_shift { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $type, amounts [ $($amount),+ ] $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty, amounts [ $amount:ty $(, $rest:ty )* ]
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type, $amount
+            where $type: $impl<$amount>, $($($bound)*)?
+        }
+
+        $crate::forward_ref_shift! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type, amounts [ $($rest),* ]
+            $( where $($bound)* )?
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty, amounts []
+        $( where $($bound:tt)* )?
+    ) => {};
+}
+
+/// For types `T: Copy`, `U: Copy` for which [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html), [`Sub`](https://doc.rust-lang.org/std/ops/trait.Sub.html), [`Mul`](https://doc.rust-lang.org/std/ops/trait.Mul.html) and [`Div`](https://doc.rust-lang.org/std/ops/trait.Div.html) are implemented (`T + U`, `T - U`, `T * U` and `T / U`), invoke [`forward_ref_binop`] for all four of them.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl for LHS(, RHS)?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `LHS` is the type of the left hand side of the operations (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the operations (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+#[macro_export]
+macro_rules! forward_ref_arith {
+    // if no RHS was given, assume RHS = LHS
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl for $lhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_arith! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl for $lhs, $lhs
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        // `forward_ref_binop` names the traits as given, so they have to be in scope
+        const _: () = {
+            use ::core::ops::{Add, Div, Mul, Sub};
+
+            $crate::forward_ref_binop! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Add for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_binop! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Sub for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_binop! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Mul for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+
+            $crate::forward_ref_binop! {
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Div for $lhs, $rhs
+                $( where $($bound)* )?
+            }
+        };
+    };
+}
+
+/// For types `T: Copy`, `S: Copy`, implement binary operator `binop` as `T binop S` with the given body, as well as all variants with references using [`forward_ref_binop`].
+///
+/// This is meant for operations between a container type and a scalar, where the scalar is broadcast to every element.
+/// The output of the operation is `T`.
+///
+/// Only `T binop S` and its variants with references are implemented, never `S binop T`, so non-commutative operations like `Sub`, `Div` or `Rem` can be implemented just like `Mul`.
+/// For a commutative operation, [`commutative_scalar_mul`] additionally implements `S binop T`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? for Type, Scalar { |lhs, rhs| Body }
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `Type` is the type the operation is implemented on (i.e. `T`)
+/// - `Scalar` is the type of the scalar (i.e. `S`)
+/// - `lhs` and `rhs` are patterns the operands are bound to in `Body`
+/// - `Body` is an expression of type `Type` computing the result of the operation
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Mul;
+/// use forward_ref_generic::impl_scalar_binop;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Vector<T, const N: usize>([T; N]);
+///
+/// impl_scalar_binop! {
+///     [T, const N: usize]
+///     impl Mul for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x * s)) }
+///     where T: Copy + Mul<Output = T>
+/// }
+///
+/// let v = Vector([1, 2, 3]);
+/// assert_eq!(&v * &2, Vector([2, 4, 6]));
+/// ```
+///
+/// The reverse direction is not implemented:
+/// ```compile_fail
+/// use std::ops::Div;
+/// use forward_ref_generic::impl_scalar_binop;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Vector<T, const N: usize>([T; N]);
+///
+/// impl_scalar_binop! {
+///     [T, const N: usize]
+///     impl Div for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x / s)) }
+///     where T: Copy + Div<Output = T>
+/// }
+///
+/// // error[E0277]: cannot divide `{integer}` by `Vector<{integer}, 3>`
+/// let _ = 2 / Vector([1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! impl_scalar_binop {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $type:ty, $scalar:ty { $($body:tt)* }
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary impl_scalar_binop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $type, $scalar { $($body)* } $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty, $scalar:ty
+        { |$lhs:pat_param, $rhs:pat_param| $body:expr }
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $impl<$scalar> for $type
+        $(where
+            $($bound)*)?
+        {
+            type Output = $type;
+
+            fn $meth(self, rhs: $scalar) -> Self::Output {
+                let $lhs = self;
+                let $rhs = rhs;
+                $body
+            }
+        }
+
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type, $scalar
+            $( where $($bound)* )?
+        }
+    };
+}
+
+/// For a type `T: Copy` wrapping a fixed-size array in a single field, implement binary operator `binop` as `T binop T` by applying `binop` to each pair of elements, as well as all variants with references using [`forward_ref_binop`].
+///
+/// The output of the operation is `T`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? for Type; field = Field
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `Type` is the type the operation is implemented on (i.e. `T`)
+/// - `Field` is the name (or index) of the field holding the array
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// `Field` has to be the only field of `Type`, as the result is constructed as `Self { Field: result }`.
+/// Like the other macros, the elements are combined by calling `Trait::Method`, so `Trait` has to be in scope.
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Add;
+/// use forward_ref_generic::componentwise_binop;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Vector<T, const N: usize>([T; N]);
+///
+/// componentwise_binop! {
+///     [T, const N: usize]
+///     impl Add for Vector<T, N>; field = 0
+///     where T: Copy + Add<Output = T>
+/// }
+///
+/// let v = Vector([1, 2, 3]);
+/// assert_eq!(&v + &v, Vector([2, 4, 6]));
+/// ```
+#[macro_export]
+macro_rules! componentwise_binop {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $type:ty; field = $field:tt
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary componentwise_binop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $type; field = $field $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty; field = $field:tt
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $impl for $type
+        $(where
+            $($bound)*)?
+        {
+            type Output = $type;
+
+            fn $meth(self, rhs: Self) -> Self::Output {
+                let mut result = self.$field;
+                for (lhs, rhs) in result.iter_mut().zip(rhs.$field) {
+                    *lhs = $impl::$meth(*lhs, rhs);
+                }
+                Self { $field: result }
+            }
+        }
+
+        $crate::forward_ref_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type
+            $( where $($bound)* )?
+        }
+    };
+}
+
+/// For types `T`, `U`, implement binary operator `binop` as `T binop U` with the given body, and also implement `U binop T` using [`commutative_binop`].
+/// This macro will fail if `LHS` = `RHS`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? for LHS, RHS { |lhs(: LHS)?, rhs(: RHS)?| -> Output { Body } }
+/// ( ; forward_ref )?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `LHS` is the type of the left hand side of the original operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the original operation (i.e. `U`)
+/// - `lhs` and `rhs` are the names the operands are bound to in `Body`, optionally annotated with their types
+/// - `Output` is the output type of the operation
+/// - `Body` are the statements computing the result of the operation
+/// - `forward_ref` additionally implements all variants with references using [`forward_ref_commutative_binop`]\
+///   this requires `T: Copy` and `U: Copy`
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Add;
+/// use forward_ref_generic::impl_commutative_binop;
+///
+/// #[derive(Clone, Copy)]
+/// struct Int1(i32);
+///
+/// #[derive(Clone, Copy)]
+/// struct Int2(i32);
+///
+/// impl_commutative_binop! {
+///     impl Add for Int1, Int2 { |a: Int1, b: Int2| -> i32 { a.0 + b.0 } }
+///     ; forward_ref
+/// }
+///
+/// assert_eq!(Int1(5) + Int2(3), 8);
+/// assert_eq!(&Int2(3) + &Int1(5), 8);
+/// ```
+#[macro_export]
+macro_rules! impl_commutative_binop {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty, $rhs:ty { $($body:tt)* }
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary impl_commutative_binop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs, $rhs { $($body)* } $( ; $option )? $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        { |$a:ident $(: $a_ty:ty)?, $b:ident $(: $b_ty:ty)?| -> $output:ty { $($body:tt)* } }
+        $( ; $option:ident )?
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $impl<$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            type Output = $output;
+
+            fn $meth(self, rhs: $rhs) -> Self::Output {
+                let $a $(: $a_ty)? = self;
+                let $b $(: $b_ty)? = rhs;
+                $($body)*
+            }
+        }
+
+        $crate::commutative_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+
+        $crate::impl_commutative_binop! {
+            @option [ $($option)? ]
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        @option []
+        $($rest:tt)*
+    ) => {};
+    (
+        @option [ forward_ref ]
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_commutative_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+    };
+    (
+        @option [ $other:ident ]
+        $($rest:tt)*
+    ) => {
+        compile_error!(concat!("unknown option `", stringify!($other), "`, expected `forward_ref`"));
+    };
+}
+
+/// For types `T: Clone`, `U: Clone` for which binary operator `binop` is implemented (`T binop U`), also implement `U binop T`, as well as all variants with references of both using [`forward_ref_binop_clone`].
+/// This macro will fail if `LHS` = `RHS`.
+///
+/// `U binop T` moves both operands into `T binop U`, so it never clones.
+/// The variants with references clone only the borrowed operands, like [`forward_ref_binop_clone`] does.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? for LHS, RHS
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented
+/// - `Method` is the method that `Trait` defines\
+///   (can be ommitted for all binary operators in [`std::ops`](https://doc.rust-lang.org/std/ops), see [Custom operators](crate#custom-operators))
+/// - `LHS` is the type of the left hand side of the original operation (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the original operation (i.e. `U`)
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+#[macro_export]
+macro_rules! commutative_binop_clone {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            binary commutative_binop_clone { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $lhs, $rhs $( where $($bound)* )? }
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::commutative_binop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $lhs, $rhs
+            $( where $($bound)* )?
+        }
+
+        $crate::forward_ref_binop_clone! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
             impl $impl, $meth for $lhs, $rhs
             $( where $($bound)* )?
         }
 
-        forward_ref_generic::forward_ref_binop! {
+        $crate::forward_ref_binop_clone! {
+            $( #[cfg $cfg] )*
             $( [ $($generic)* ] )?
             impl $impl, $meth for $rhs, $lhs
             $( where $($bound)* )?
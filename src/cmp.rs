@@ -0,0 +1,194 @@
+//! The macros for comparison traits in [`std::cmp`](https://doc.rust-lang.org/std/cmp).
+//!
+//! These are the same macros as in the crate root, re-exported so they can be imported as e.g. `forward_ref_generic::cmp::forward_ref_ord`.
+
+pub use crate::{commutative_partial_eq, forward_ref_ord};
+
+/// For types `T`, `U` for which [`PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html) is implemented (`T: PartialOrd<U>`), also implement comparisons between `T` and `&U` as well as `&T` and `U`.
+///
+/// The standard library already implements `PartialEq<&U>` and `PartialOrd<&U>` for `&T`, as well as [`Ord`](https://doc.rust-lang.org/std/cmp/trait.Ord.html) for `&T`.
+/// However, there are no such implementations for comparing an owned value with a reference, so `a < &b` and `&a < b` do not compile.
+/// This macro implements [`PartialEq`](https://doc.rust-lang.org/std/cmp/trait.PartialEq.html) and [`PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html) for exactly those two variants, forwarding every method of `PartialOrd` (including custom implementations of e.g. `lt`) to the original implementation.
+/// Since comparisons only take references, neither `T` nor `U` have to be `Copy`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl PartialOrd for LHS(, RHS)?
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `LHS` is the type of the left hand side of the comparison (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the comparison (i.e. `U`)\
+///   if no `RHS` is given, `LHS` = `RHS` is assumed
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// `&T` compared with `&U` is not implemented, as the standard library already does so by dereferencing both sides.
+/// Comparing references therefore keeps working after invoking the macro:
+/// ```
+/// use forward_ref_generic::forward_ref_ord;
+///
+/// #[derive(PartialEq, PartialOrd)]
+/// struct Meters(f64);
+///
+/// forward_ref_ord! {
+///     impl PartialOrd for Meters
+/// }
+///
+/// let (a, b) = (Meters(1.0), Meters(2.0));
+/// assert!(a < &b);
+/// assert!(&a < b);
+/// // uses the standard library's `impl PartialOrd<&B> for &A`
+/// assert!(&a < &b);
+/// ```
+///
+/// For the same reason, `RHS` must not be a bare generic parameter, as it could be a reference itself:
+/// ```compile_fail
+/// use forward_ref_generic::forward_ref_ord;
+///
+/// #[derive(PartialEq, PartialOrd)]
+/// struct Meters(f64);
+///
+/// // error[E0119]: conflicting implementations of trait `PartialEq<&_>` for type `&Meters`
+/// forward_ref_ord! {
+///     [U]
+///     impl PartialOrd for Meters, U
+///     where Meters: PartialOrd<U>
+/// }
+/// ```
+#[macro_export]
+macro_rules! forward_ref_ord {
+    // if no RHS was given, assume RHS = LHS
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl PartialOrd for $lhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::forward_ref_ord! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl PartialOrd for $lhs, $lhs
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl PartialOrd for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::cmp::PartialEq<&$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            fn eq(&self, other: &&$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialEq<$rhs>>::eq(self, *other)
+            }
+        }
+
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::cmp::PartialEq<$rhs> for &$lhs
+        $(where
+            $($bound)*)?
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialEq<$rhs>>::eq(*self, other)
+            }
+        }
+
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::cmp::PartialOrd<&$rhs> for $lhs
+        $(where
+            $($bound)*)?
+        {
+            fn partial_cmp(&self, other: &&$rhs) -> ::core::option::Option<::core::cmp::Ordering> {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::partial_cmp(self, *other)
+            }
+
+            fn lt(&self, other: &&$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::lt(self, *other)
+            }
+
+            fn le(&self, other: &&$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::le(self, *other)
+            }
+
+            fn gt(&self, other: &&$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::gt(self, *other)
+            }
+
+            fn ge(&self, other: &&$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::ge(self, *other)
+            }
+        }
+
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::cmp::PartialOrd<$rhs> for &$lhs
+        $(where
+            $($bound)*)?
+        {
+            fn partial_cmp(&self, other: &$rhs) -> ::core::option::Option<::core::cmp::Ordering> {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::partial_cmp(*self, other)
+            }
+
+            fn lt(&self, other: &$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::lt(*self, other)
+            }
+
+            fn le(&self, other: &$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::le(*self, other)
+            }
+
+            fn gt(&self, other: &$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::gt(*self, other)
+            }
+
+            fn ge(&self, other: &$rhs) -> bool {
+                <$lhs as ::core::cmp::PartialOrd<$rhs>>::ge(*self, other)
+            }
+        }
+    };
+}
+
+/// For types `T`, `U` for which [`PartialEq`](https://doc.rust-lang.org/std/cmp/trait.PartialEq.html) is implemented (`T: PartialEq<U>`), also implement `U: PartialEq<T>`.
+/// This macro will fail if `LHS` = `RHS`.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl PartialEq for LHS, RHS
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `LHS` is the type of the left hand side of the original comparison (i.e. `T`)
+/// - `RHS` is the type of the right hand side of the original comparison (i.e. `U`)
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// Like for [`commutative_binop`](crate::commutative_binop), `LHS` and `RHS` denote the left and right side of the **original** comparison, not the one being created.
+/// If `LHS` = `RHS`, the generated implementation is the original one itself, resulting in conflicting implementations.
+#[macro_export]
+macro_rules! commutative_partial_eq {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl PartialEq for $lhs:ty, $rhs:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::cmp::PartialEq<$lhs> for $rhs
+        $(where
+            $($bound)*)?
+        {
+            fn eq(&self, other: &$lhs) -> bool {
+                <$lhs as ::core::cmp::PartialEq<$rhs>>::eq(other, self)
+            }
+        }
+    };
+}
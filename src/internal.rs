@@ -0,0 +1,257 @@
+/// Expands to its input unchanged.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_identity {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+/// Looks up the method of a known operator trait of the given category and invokes `$mac` with it.
+///
+/// The category is one of `unary`, `binary` or `assign`, so that e.g. `Add` is only recognized by the binary macros.
+/// `$mac` is invoked as `$mac! { Pre impl Trait, method Post }`,
+/// so the method is inserted right after the trait like when specifying it explicitly.
+/// This is the only place where operator traits are mapped to their methods.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_method {
+    // unary operators
+    (unary $mac:ident { $($pre:tt)* } Neg { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Neg, neg $($post)* }
+    };
+    (unary $mac:ident { $($pre:tt)* } Not { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Not, not $($post)* }
+    };
+
+    // binary operators
+    (binary $mac:ident { $($pre:tt)* } Add { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Add, add $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Sub { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Sub, sub $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Mul { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Mul, mul $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Div { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Div, div $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Rem { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Rem, rem $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } BitAnd { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitAnd, bitand $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } BitOr { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitOr, bitor $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } BitXor { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitXor, bitxor $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Shl { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Shl, shl $($post)* }
+    };
+    (binary $mac:ident { $($pre:tt)* } Shr { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl Shr, shr $($post)* }
+    };
+
+    // assignment operators
+    (assign $mac:ident { $($pre:tt)* } AddAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl AddAssign, add_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } SubAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl SubAssign, sub_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } MulAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl MulAssign, mul_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } DivAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl DivAssign, div_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } RemAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl RemAssign, rem_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } BitAndAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitAndAssign, bitand_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } BitOrAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitOrAssign, bitor_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } BitXorAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl BitXorAssign, bitxor_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } ShlAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl ShlAssign, shl_assign $($post)* }
+    };
+    (assign $mac:ident { $($pre:tt)* } ShrAssign { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* impl ShrAssign, shr_assign $($post)* }
+    };
+
+    // unknown traits or traits of another category
+    (unary $mac:ident { $($pre:tt)* } $impl:ident { $($post:tt)* }) => {
+        compile_error!(concat!(
+            "cannot infer the method of `",
+            stringify!($impl),
+            "` as a unary operator, specify it explicitly with `impl ",
+            stringify!($impl),
+            ", method for ...`",
+        ));
+    };
+    (binary $mac:ident { $($pre:tt)* } $impl:ident { $($post:tt)* }) => {
+        compile_error!(concat!(
+            "cannot infer the method of `",
+            stringify!($impl),
+            "` as a binary operator, specify it explicitly with `impl ",
+            stringify!($impl),
+            ", method for ...`",
+        ));
+    };
+    (assign $mac:ident { $($pre:tt)* } $impl:ident { $($post:tt)* }) => {
+        compile_error!(concat!(
+            "cannot infer the method of `",
+            stringify!($impl),
+            "` as an assignment operator, specify it explicitly with `impl ",
+            stringify!($impl),
+            ", method for ...`",
+        ));
+    };
+}
+
+/// Splits the input into an expression and an optional where clause and invokes `$mac` with them.
+///
+/// `$mac` is invoked as `$mac! { Pre { Expr } ( where Bounds )? }`.
+/// An `expr` fragment cannot be followed by `where` in a macro pattern,
+/// so the expression is munched token by token until `where` or the end of the input.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_split_where {
+    ($mac:ident { $($pre:tt)* } [ $($expr:tt)* ] where $($bound:tt)*) => {
+        $crate::$mac! { $($pre)* { $($expr)* } where $($bound)* }
+    };
+    ($mac:ident { $($pre:tt)* } [ $($expr:tt)* ]) => {
+        $crate::$mac! { $($pre)* { $($expr)* } }
+    };
+    ($mac:ident { $($pre:tt)* } [ $($expr:tt)* ] $next:tt $($rest:tt)*) => {
+        $crate::__forward_ref_split_where! { $mac { $($pre)* } [ $($expr)* $next ] $($rest)* }
+    };
+}
+
+/// Sorts the leading attributes of the input and invokes `$mac` with them.
+///
+/// `$mac` is invoked as `$mac! { Pre [ Cfgs ] [ Attrs ] Rest }`,
+/// where `Cfgs` are the `#[cfg(...)]` attributes that go on the generated implementations
+/// and `Attrs` are all other attributes, which go on the generated methods.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_attrs {
+    ($mac:ident { $($pre:tt)* } [ $($cfg:tt)* ] [ $($attr:tt)* ] #[cfg $predicate:tt] $($rest:tt)*) => {
+        $crate::__forward_ref_attrs! { $mac { $($pre)* } [ $($cfg)* #[cfg $predicate] ] [ $($attr)* ] $($rest)* }
+    };
+    ($mac:ident { $($pre:tt)* } [ $($cfg:tt)* ] [ $($attr:tt)* ] #[ $($meta:tt)* ] $($rest:tt)*) => {
+        $crate::__forward_ref_attrs! { $mac { $($pre)* } [ $($cfg)* ] [ $($attr)* #[ $($meta)* ] ] $($rest)* }
+    };
+    ($mac:ident { $($pre:tt)* } [ $($cfg:tt)* ] [ $($attr:tt)* ] $($rest:tt)*) => {
+        $crate::$mac! { $($pre)* [ $($cfg)* ] [ $($attr)* ] $($rest)* }
+    };
+}
+
+/// Merges two lists of generics and invokes `$mac` with the result.
+///
+/// `$mac` is invoked as `$mac! { Pre [ Generics ] Post }`,
+/// where `Generics` are those of the first list followed by those of the second list that are not already listed in the first one.
+/// Macros cannot compare identifiers, so this defines a local macro with an arm for every name of the first list, which filters the second list.
+/// The local macro is defined in an anonymous constant for `item` and in a block expression for `expr`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_union {
+    ($pos:ident $mac:ident { $($pre:tt)* } [ $($lhs:tt)* ] [ $($rhs:tt)* ] { $($post:tt)* }) => {
+        $crate::__forward_ref_union! {
+            @lhs { ($) $pos $mac { $($pre)* } { $($post)* } }
+            [] [] [ $($lhs)* ] [ $($rhs)* ]
+        }
+    };
+
+    // collect the names of the first list, each parameter of the union is preceded by a comma
+    (@lhs $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [ $(,)? ] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @emit $ctx [ $($name)* ] [ $($union)* ] [ $($rhs)* ] }
+    };
+    (@lhs $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [ const $next:tt $($lhs:tt)* ] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @lhs_rest $ctx [ $($name)* $next ] [ $($union)* , const $next ] [ $($lhs)* ] [ $($rhs)* ] }
+    };
+    (@lhs $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [ $next:tt $($lhs:tt)* ] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @lhs_rest $ctx [ $($name)* $next ] [ $($union)* , $next ] [ $($lhs)* ] [ $($rhs)* ] }
+    };
+    (@lhs_rest $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [ , $($lhs:tt)* ] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @lhs $ctx [ $($name)* ] [ $($union)* ] [ $($lhs)* ] [ $($rhs)* ] }
+    };
+    (@lhs_rest $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [ $next:tt $($lhs:tt)* ] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @lhs_rest $ctx [ $($name)* ] [ $($union)* $next ] [ $($lhs)* ] [ $($rhs)* ] }
+    };
+    (@lhs_rest $ctx:tt [ $($name:tt)* ] [ $($union:tt)* ] [] [ $($rhs:tt)* ]) => {
+        $crate::__forward_ref_union! { @emit $ctx [ $($name)* ] [ $($union)* ] [ $($rhs)* ] }
+    };
+
+    (@emit { ($d:tt) item $($ctx:tt)* } $($rest:tt)*) => {
+        const _: () = {
+            $crate::__forward_ref_union! { @define { ($d) $($ctx)* } $($rest)* }
+        };
+    };
+    (@emit { ($d:tt) expr $($ctx:tt)* } $($rest:tt)*) => {
+        {
+            $crate::__forward_ref_union! { @define { ($d) $($ctx)* } $($rest)* }
+        }
+    };
+
+    // filter the second list, `$d` is a `$` for the local macro's own metavariables
+    (
+        @define { ($d:tt) $mac:ident { $($pre:tt)* } { $($post:tt)* } }
+        [ $($name:tt)* ] [ $($union:tt)* ] [ $($rhs:tt)* ]
+    ) => {
+        macro_rules! __forward_ref_union_rhs {
+            ([ $d($d union:tt)* ] [ $d(,)? ]) => {
+                $crate::__forward_ref_union! { @done $mac { $($pre)* } [ $d($d union)* ] { $($post)* } }
+            };
+
+            // parameters already listed in the first list are left out
+            $(
+                ([ $d($d union:tt)* ] [ $d(const)? $name $d($d rhs:tt)* ]) => {
+                    __forward_ref_union_rhs! { @skip [ $d($d union)* ] [ $d($d rhs)* ] }
+                };
+            )*
+            ([ $d($d union:tt)* ] [ $d($d rhs:tt)* ]) => {
+                __forward_ref_union_rhs! { @keep [ $d($d union)* , ] [ $d($d rhs)* ] }
+            };
+
+            (@skip [ $d($d union:tt)* ] [ , $d($d rhs:tt)* ]) => {
+                __forward_ref_union_rhs! { [ $d($d union)* ] [ $d($d rhs)* ] }
+            };
+            (@skip [ $d($d union:tt)* ] [ $d next:tt $d($d rhs:tt)* ]) => {
+                __forward_ref_union_rhs! { @skip [ $d($d union)* ] [ $d($d rhs)* ] }
+            };
+            (@skip [ $d($d union:tt)* ] []) => {
+                __forward_ref_union_rhs! { [ $d($d union)* ] [] }
+            };
+
+            (@keep [ $d($d union:tt)* ] [ , $d($d rhs:tt)* ]) => {
+                __forward_ref_union_rhs! { [ $d($d union)* ] [ $d($d rhs)* ] }
+            };
+            (@keep [ $d($d union:tt)* ] [ $d next:tt $d($d rhs:tt)* ]) => {
+                __forward_ref_union_rhs! { @keep [ $d($d union)* $d next ] [ $d($d rhs)* ] }
+            };
+            (@keep [ $d($d union:tt)* ] []) => {
+                __forward_ref_union_rhs! { [ $d($d union)* ] [] }
+            };
+        }
+
+        __forward_ref_union_rhs! { [ $($union)* ] [ $($rhs)* ] }
+    };
+
+    // drop the comma in front of the first parameter
+    (@done $mac:ident { $($pre:tt)* } [ , $($union:tt)* ] { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* [ $($union)* ] $($post)* }
+    };
+    (@done $mac:ident { $($pre:tt)* } [] { $($post:tt)* }) => {
+        $crate::$mac! { $($pre)* [] $($post)* }
+    };
+}
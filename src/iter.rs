@@ -0,0 +1,179 @@
+//! The macros for iterator traits in [`std::iter`](https://doc.rust-lang.org/std/iter).
+//!
+//! These are the same macros as in the crate root, re-exported so they can be imported as e.g. `forward_ref_generic::iter::sum_from_add`.
+
+pub use crate::{product_from_mul, sum_from_add};
+
+/// For a type `T: Copy` for which [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html) is implemented with output `T` (`T + T`), implement [`Sum`](https://doc.rust-lang.org/std/iter/trait.Sum.html) for iterators over `T` and `&T` by folding with `+`, starting from the given identity.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Sum for Type; zero = Zero
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Type` is the type the iterators are summed up into (i.e. `T`)
+/// - `Zero` is an expression of type `Type` that is the identity of `+`, i.e. the sum of an empty iterator
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Add;
+/// use forward_ref_generic::sum_from_add;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl Add for Meters {
+///     type Output = Self;
+///
+///     fn add(self, rhs: Self) -> Self::Output {
+///         Self(self.0 + rhs.0)
+///     }
+/// }
+///
+/// sum_from_add! {
+///     impl Sum for Meters; zero = Meters(0.0)
+/// }
+///
+/// let distances = [Meters(1.5), Meters(2.5)];
+/// assert_eq!(distances.iter().sum::<Meters>(), Meters(4.0));
+/// ```
+#[macro_export]
+macro_rules! sum_from_add {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Sum for $type:ty; zero = $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_split_where! {
+            sum_from_add {
+                @emit
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Sum for $type; zero =
+            }
+            []
+            $($rest)*
+        }
+    };
+
+    (
+        @emit
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Sum for $type:ty; zero = { $($zero:tt)* }
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::iter::Sum for $type
+        $(where
+            $($bound)*)?
+        {
+            fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($($zero)*, |acc, x| acc + x)
+            }
+        }
+
+        $( #[cfg $cfg] )*
+        impl<'item, $($($generic)*)?> ::core::iter::Sum<&'item $type> for $type
+        $(where
+            $($bound)*)?
+        {
+            fn sum<I: ::core::iter::Iterator<Item = &'item Self>>(iter: I) -> Self {
+                iter.fold($($zero)*, |acc, x| acc + *x)
+            }
+        }
+    };
+}
+
+/// For a type `T: Copy` for which [`Mul`](https://doc.rust-lang.org/std/ops/trait.Mul.html) is implemented with output `T` (`T * T`), implement [`Product`](https://doc.rust-lang.org/std/iter/trait.Product.html) for iterators over `T` and `&T` by folding with `*`, starting from the given identity.
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Product for Type; one = One
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Type` is the type the iterators are multiplied into (i.e. `T`)
+/// - `One` is an expression of type `Type` that is the identity of `*`, i.e. the product of an empty iterator
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Mul;
+/// use forward_ref_generic::product_from_mul;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Factor(f64);
+///
+/// impl Mul for Factor {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self::Output {
+///         Self(self.0 * rhs.0)
+///     }
+/// }
+///
+/// product_from_mul! {
+///     impl Product for Factor; one = Factor(1.0)
+/// }
+///
+/// let factors = [Factor(1.5), Factor(2.0)];
+/// assert_eq!(factors.iter().product::<Factor>(), Factor(3.0));
+/// ```
+#[macro_export]
+macro_rules! product_from_mul {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Product for $type:ty; one = $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_split_where! {
+            product_from_mul {
+                @emit
+                $( #[cfg $cfg] )*
+                $( [ $($generic)* ] )?
+                impl Product for $type; one =
+            }
+            []
+            $($rest)*
+        }
+    };
+
+    (
+        @emit
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl Product for $type:ty; one = { $($one:tt)* }
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? ::core::iter::Product for $type
+        $(where
+            $($bound)*)?
+        {
+            fn product<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($($one)*, |acc, x| acc * x)
+            }
+        }
+
+        $( #[cfg $cfg] )*
+        impl<'item, $($($generic)*)?> ::core::iter::Product<&'item $type> for $type
+        $(where
+            $($bound)*)?
+        {
+            fn product<I: ::core::iter::Iterator<Item = &'item Self>>(iter: I) -> Self {
+                iter.fold($($one)*, |acc, x| acc * *x)
+            }
+        }
+    };
+}
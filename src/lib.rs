@@ -9,10 +9,34 @@
 //! That is to say, if one has a type like `Point<T> {x: T, y: T}`, so far it was necessary to implement all variants by hand.
 //! This crate offers macros that also support generic types, including trait bounds, so the only assumption left is that the type the operation is implemented on is `Copy`.
 //!
+//! A blanket implementation like `impl<T: Copy + Add<U>, U: Copy> Add<&U> for T` cannot replace the macros, as Rust's [orphan rules](https://doc.rust-lang.org/reference/items/implementations.html#orphan-rules) do not allow implementing a foreign trait like `Add` for a type parameter `T`. The variants therefore have to be implemented for each type separately, which is what these macros automate.
+//!
 //! There are seperate macros offered for types of operations:
 //! * Unary Operators like [`Neg`](https://doc.rust-lang.org/std/ops/trait.Neg.html): [`forward_ref_unop`]
 //! * Binary Operators like [`Add`](https://doc.rust-lang.org/std/ops/trait.Add.html): [`forward_ref_binop`]
 //! * Assignment Operators like [`AddAssign`](https://doc.rust-lang.org/std/ops/trait.AddAssign.html): [`forward_ref_op_assign`]
+//! * Comparison Operators like [`PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html): [`forward_ref_ord`]
+//!
+//! For binary operators on types that are `Clone` but not `Copy`, [`forward_ref_binop_clone`] implements the same variants by cloning only the borrowed operands.
+//! For two such types, [`commutative_binop_clone`] additionally implements the reversed operation and the variants with references of both.
+//! If only `&T binop &U` is wanted, [`forward_ref_binop_refs`] implements just that variant.
+//! For shift operators, [`forward_ref_shift`] implements the variants of [`forward_ref_binop`] for both `Shl` and `Shr` and every integer type as the shift amount at once, like the standard library does.
+//! To forward `Add`, `Sub`, `Mul` and `Div` at once, [`forward_ref_arith`] invokes [`forward_ref_binop`] for all four of them.
+//! Likewise, [`forward_ref_arith_assign`] invokes [`forward_ref_op_assign`] for `AddAssign`, `SubAssign`, `MulAssign` and `DivAssign`.
+//! For operations between a container type and a scalar, [`impl_scalar_binop`] implements the operation from a closure-like body and forwards it in one go.
+//! Similarly, [`impl_unop`] implements a unary operator, including custom ones with a different output, from a closure-like body and forwards it.
+//! For types wrapping a fixed-size array, [`componentwise_binop`] implements an operation elementwise and forwards it as well.
+//! For commutative operations between two different types, [`impl_commutative_binop`] implements the operation from a closure-like body in both directions.
+//! To inspect the implementations [`forward_ref_binop`] generates, [`forward_ref_binop_debug`] returns them as a string instead.
+//!
+//! Additionally, there are macros to implement operators based on other operators:
+//! * `Sub` from `Add` and `Neg`: [`derive_sub_from_add_neg`]
+//! * `ShlAssign` and `ShrAssign` from `Shl` and `Shr`: [`derive_shift_assign_from_shift`]
+//! * [`Sum`](https://doc.rust-lang.org/std/iter/trait.Sum.html) from `Add`: [`sum_from_add`]
+//! * [`Product`](https://doc.rust-lang.org/std/iter/trait.Product.html) from `Mul`: [`product_from_mul`]
+//!
+//! All macros are exported at the crate root, and additionally grouped into the modules [`ops`], [`cmp`] and [`iter`], so e.g. `use forward_ref_generic::ops::forward_ref_binop;` works as well.
+//! To import all of them at once, use the [`prelude`]: `use forward_ref_generic::prelude::*;`.
 //!
 //! # Examples
 //!
@@ -200,6 +224,8 @@
 //! assert_eq!(&p1 + &p2, Point { x: 8, y: 5 });
 //! ```
 //!
+//! The generics are used for the generated `impl` blocks exactly as they are given. As implementations cannot have defaults for their generics, defaults like in `struct Grid<T = f64, const N: usize = 3>` have to be left out, i.e. `[T, const N: usize]`.
+//!
 //! ## Const generics and different RHS
 //!
 //! So far, the right hand side of the operation was of the same type as the left hand side.
@@ -293,8 +319,8 @@
 //!
 //! Notice that in all previous examples, all information the macro required on *which* operation is supposed to be implemented was the Trait's name.
 //! This is done by specifically checking for known Operator Traits and inserting the required method's name from inside the macro.
-//! This is currently **only** done for standard mathematical operators (i.e. not for bitwise operators and not for custom operators).
-//! However, one can still use the macros, but the method's name has to be specified in that case. RHS can again be omitted if LHS = RHS:
+//! This is done for all operator traits in [`std::ops`](https://doc.rust-lang.org/std/ops), i.e. `Neg` and `Not`, `Add`, `Sub`, `Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`, `Shl` and `Shr` as well as their `*Assign` counterparts.
+//! For custom operators, one can still use the macros, but the method's name has to be specified in that case. RHS can again be omitted if LHS = RHS:
 //!
 //! ```ignore
 //! forward_ref_binop! {
@@ -329,6 +355,7 @@
 //! }
 //!
 //! // this time we use the macro for unary operators and specify the `not` method's name
+//! // (even though it could be ommitted for `Not`)
 //! forward_ref_unop! {
 //!     impl Not, not for Answer
 //! }
@@ -342,7 +369,7 @@
 //!
 //! ### Making an operation commutative
 //!
-//! There are also macros to automatically make an operation commutative. That is, for two types `T` and `U`, if `T binop U` is implemented, then one can use [`commutative_binop`] to automatically implement `U binop T`. If `T` and `U` are additionally `Copy`, then `T binop &U`, `&T binop U`, `&T binop &U`, `U binop &T`, `&U binop T` and `&U binop &T` can automatically be implemented with [`forward_ref_commutative_binop`].
+//! There are also macros to automatically make an operation commutative. That is, for two types `T` and `U`, if `T binop U` is implemented, then one can use [`commutative_binop`] to automatically implement `U binop T`. If `T` and `U` are additionally `Copy`, then `T binop &U`, `&T binop U`, `&T binop &U`, `U binop &T`, `&U binop T` and `&U binop &T` can automatically be implemented with [`forward_ref_commutative_binop`]. For operations between a type and a list of scalar types, [`commutative_scalar_mul`] does both at once. For comparisons, [`commutative_partial_eq`] implements `U: PartialEq<T>` from `T: PartialEq<U>`.
 //!
 //! ```
 //! use std::ops::Add;
@@ -390,7 +417,88 @@
 //! assert_eq!(i2 + &i1, 8);
 //! assert_eq!(&i2 + &i1, 8);
 //! ```
+//!
+//! ## Conditional compilation
+//!
+//! All macros accept `#[cfg(...)]` attributes in front of their input, which are put on every implementation the macro generates.
+//! This way, the entire set of generated implementations is gated by the same predicate:
+//!
+//! ```
+//! use std::ops::Add;
+//! use forward_ref_generic::forward_ref_binop;
+//!
+//! #[derive(Debug, Copy, Clone, PartialEq)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl Add for Point {
+//!     type Output = Self;
+//!
+//!     fn add(self, rhs: Self) -> Self::Output {
+//!         Self {x: self.x + rhs.x, y: self.y + rhs.y}
+//!     }
+//! }
+//!
+//! forward_ref_binop! {
+//!     #[cfg(target_pointer_width = "64")]
+//!     impl Add for Point
+//! }
+//! ```
+//!
+//! Gating only some of the generated implementations is not possible with a single invocation.
+//! That requires separate invocations of macros that generate fewer implementations, or implementing those variants by hand.
+//!
+//! [`forward_ref_binop`], [`forward_ref_unop`] and [`forward_ref_op_assign`] additionally accept any other attributes in front of their input, which are put on every generated method instead. This includes `cfg_attr`, so e.g. `#[cfg_attr(feature = "fast", inline(always))]` only inlines the methods if the `fast` feature of the invoking crate is enabled.
+//!
+//! ## Const implementations
+//!
+//! On a nightly compiler, [`forward_ref_binop`], [`forward_ref_binop_refs`], [`forward_ref_unop`] and [`forward_ref_op_assign`] generate `impl const` blocks if the trait is given as `impl const Trait`, so the variants with references can be used in const contexts:
+//! ```ignore
+//! #![feature(const_trait_impl, const_ops)]
+//!
+//! use std::ops::Add;
+//! use forward_ref_generic::forward_ref_binop;
+//!
+//! #[derive(Clone, Copy)]
+//! struct Int(i32);
+//!
+//! impl const Add for Int {
+//!     type Output = Self;
+//!
+//!     fn add(self, rhs: Self) -> Self::Output {
+//!         Self(self.0 + rhs.0)
+//!     }
+//! }
+//!
+//! forward_ref_binop! {
+//!     impl const Add for Int
+//! }
+//!
+//! const SUM: Int = &Int(1) + &Int(2);
+//! ```
+//!
+//! The original implementation the generated ones forward to has to be const as well. Invocations without `const` are not affected, so they still work on a stable compiler.
+//!
+//! # Features
+//!
+//! ## `testing`
+//!
+//! The `testing` feature enables [`assert_forward_ref_binop`](https://docs.rs/forward_ref_generic/*/forward_ref_generic/macro.assert_forward_ref_binop.html), which asserts that all variants with references of a binary operation equal the expected value:
+//! ```ignore
+//! assert_forward_ref_binop!(a + b => expected);
+//! ```
+//!
+//! As it is only meant for tests, it is best enabled in `[dev-dependencies]`.
 
 mod assignment;
 mod binary;
+pub mod cmp;
+mod internal;
+pub mod iter;
+pub mod ops;
+pub mod prelude;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod unary;
@@ -0,0 +1,11 @@
+//! The macros for operators in [`std::ops`](https://doc.rust-lang.org/std/ops).
+//!
+//! These are the same macros as in the crate root, re-exported so they can be imported as e.g. `forward_ref_generic::ops::forward_ref_binop`.
+
+pub use crate::{
+    commutative_binop, commutative_binop_clone, commutative_scalar_mul, componentwise_binop,
+    derive_shift_assign_from_shift, derive_sub_from_add_neg, forward_ref_arith,
+    forward_ref_arith_assign, forward_ref_binop, forward_ref_binop_clone, forward_ref_binop_debug,
+    forward_ref_binop_refs, forward_ref_commutative_binop, forward_ref_op_assign,
+    forward_ref_shift, forward_ref_unop, impl_commutative_binop, impl_scalar_binop, impl_unop,
+};
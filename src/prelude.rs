@@ -0,0 +1,9 @@
+//! All macros of this crate, to be glob-imported with `use forward_ref_generic::prelude::*;`.
+//!
+//! This includes the macros of the `testing` feature if it is enabled.
+
+pub use crate::cmp::*;
+pub use crate::iter::*;
+pub use crate::ops::*;
+#[cfg(feature = "testing")]
+pub use crate::testing::*;
@@ -0,0 +1,57 @@
+//! Macros for testing implementations of operators, available with the `testing` feature.
+//!
+//! These are the same macros as in the crate root, re-exported so they can be imported as e.g. `forward_ref_generic::testing::assert_forward_ref_binop`.
+
+pub use crate::assert_forward_ref_binop;
+
+/// Asserts that `T binop U`, `T binop &U`, `&T binop U` and `&T binop &U` all equal the expected value.
+///
+/// The syntax of the macro is `assert_forward_ref_binop!(lhs binop rhs => expected)`.
+/// `lhs` and `rhs` each have to be a single token, i.e. a variable, a literal or an expression in parentheses, and are evaluated only once.
+/// The results are compared with [`assert_eq`], so they need to implement [`PartialEq`] with `expected` as well as [`Debug`](core::fmt::Debug).
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Add;
+/// use forward_ref_generic::{assert_forward_ref_binop, forward_ref_binop};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Int(i32);
+///
+/// impl Add for Int {
+///     type Output = Self;
+///
+///     fn add(self, rhs: Self) -> Self::Output {
+///         Self(self.0 + rhs.0)
+///     }
+/// }
+///
+/// forward_ref_binop! {
+///     impl Add for Int
+/// }
+///
+/// let a = Int(1);
+/// assert_forward_ref_binop!(a + (Int(2)) => Int(3));
+/// ```
+#[macro_export]
+macro_rules! assert_forward_ref_binop {
+    ($lhs:tt $op:tt $rhs:tt => $expected:expr $(,)?) => {
+        match (&$lhs, &$rhs, &$expected) {
+            (lhs, rhs, expected) => {
+                ::core::assert_eq!(*lhs $op *rhs, *expected, "{}", ::core::concat!(
+                    ::core::stringify!($lhs), " ", ::core::stringify!($op), " ", ::core::stringify!($rhs),
+                ));
+                ::core::assert_eq!(*lhs $op rhs, *expected, "{}", ::core::concat!(
+                    ::core::stringify!($lhs), " ", ::core::stringify!($op), " &", ::core::stringify!($rhs),
+                ));
+                ::core::assert_eq!(lhs $op *rhs, *expected, "{}", ::core::concat!(
+                    "&", ::core::stringify!($lhs), " ", ::core::stringify!($op), " ", ::core::stringify!($rhs),
+                ));
+                ::core::assert_eq!(lhs $op rhs, *expected, "{}", ::core::concat!(
+                    "&", ::core::stringify!($lhs), " ", ::core::stringify!($op), " &", ::core::stringify!($rhs),
+                ));
+            }
+        }
+    };
+}
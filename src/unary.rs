@@ -2,44 +2,312 @@
 ///
 /// For readability, the expected syntax of the macro is the following:
 /// ```text
+/// ( #[cfg(Predicate)] | #[Attribute] )*
 /// ( [ Generics ] )?
-/// impl Trait, Method for Type
+/// impl ( const )? Trait, Method for Type
 /// ( where Bounds )?
 /// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Attribute`s are any other attributes, which are put on every generated method, e.g. `#[inline]` or `#[cfg_attr(feature = "fast", inline(always))]`
 /// - `Generics` are comma-seperated type or const generics
+/// - `const` makes the generated implementation `impl const`, see [Const implementations](crate#const-implementations)
 /// - `Trait` is the trait to be implemented
 /// - `Method` is the method that `Trait` defines\
-///   (can be ommitted for [`Neg`](https://doc.rust-lang.org/std/ops/trait.Neg.html))
+///   (can be ommitted for [`Neg`](https://doc.rust-lang.org/std/ops/trait.Neg.html) and [`Not`](https://doc.rust-lang.org/std/ops/trait.Not.html))
 /// - `Type` is the type that `Trait` is implemented on (i.e. `T`)
 /// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// Multiple traits whose methods can be ommitted may also be given at once by listing them in curly braces instead:
+/// ```text
+/// ( #[cfg(Predicate)] | #[Attribute] )*
+/// ( [ Generics ] )?
+/// impl ( const )? { Trait, Trait, ... } for Type
+/// ( where Bounds )?
+/// ```
+/// This is equivalent to invoking the macro once for each listed trait with the same `Generics`, `Type` and `Bounds`.
 #[macro_export]
 macro_rules! forward_ref_unop {
+    // remember whether the implementations are const while processing the rest of the input
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl const $($rest:tt)*
+    ) => {
+        $crate::forward_ref_unop! {
+            @front [const]
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $($rest)*
+        }
+    };
+    (
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $($rest:tt)*
+    ) => {
+        $crate::forward_ref_unop! {
+            @front []
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $($rest)*
+        }
+    };
+
     (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
-        impl Neg for $type:ty
+        impl { $impl:ident $(, $rest:ident )* $(,)? } for $type:ty
         $( where $($bound:tt)* )?
     ) => {
-        forward_ref_unop! {
+        $crate::forward_ref_unop! {
+            @front [ $($constness)? ]
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $impl for $type
+            $( where $($bound)* )?
+        }
+
+        $crate::forward_ref_unop! {
+            @front [ $($constness)? ]
+            $( #[ $($attr)* ] )*
             $( [ $($generic)* ] )?
-            impl Neg, neg for $type
+            impl { $($rest),* } for $type
             $( where $($bound)* )?
         }
     };
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl { } for $type:ty
+        $( where $($bound:tt)* )?
+    ) => {};
+
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $type:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::__forward_ref_method! {
+            unary forward_ref_unop { @front [ $($constness)? ] $( #[ $($attr)* ] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $type $( where $($bound)* )? }
+        }
+    };
 
+    // sorts the attributes into those for the implementation and those for the method
     (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
         $( [ $($generic:tt)* ] )?
         impl $impl:ident, $meth:ident for $type:ty
         $( where $($bound:tt)* )?
     ) => {
-        impl$(<$($generic)*>)? $impl for &$type
+        $crate::__forward_ref_attrs! {
+            forward_ref_unop { @emit [ $($constness)? ] }
+            [] []
+            $( #[ $($attr)* ] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type
+            $( where $($bound)* )?
+        }
+    };
+
+    // `constness` is `const` if the implementation was given as `impl const`
+    (
+        @emit [ $($constness:tt)? ]
+        [ $( #[cfg $cfg:tt] )* ] [ $( #[ $($attr:tt)* ] )* ]
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $($constness)? $impl for &$type
         $(where
             $($bound)*)?
         {
             type Output = <$type as $impl>::Output;
 
+            $( #[ $($attr)* ] )*
             fn $meth(self) -> Self::Output {
                 <$type>::$meth(*self)
             }
         }
     };
+
+    // friendlier errors for common mistakes
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl < $($rest:tt)*
+    ) => {
+        compile_error!("generics have to be listed in brackets in front of `impl`, e.g. `[T] impl Neg for Point<T>`");
+    };
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $type:ty ; $($rest:tt)*
+    ) => {
+        compile_error!("unexpected `;`, the type has to be followed by `where` or the end of the input");
+    };
+    (
+        @front [ $($constness:tt)? ]
+        $( #[ $($attr:tt)* ] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident $(, $meth:ident )? for $type:ty { $($body:tt)* } $($rest:tt)*
+    ) => {
+        compile_error!("unexpected implementation body, `forward_ref_unop` generates the implementations itself");
+    };
+    ($($input:tt)*) => {
+        compile_error!("expected input of the form `( [ Generics ] )? impl ( const )? Trait(, Method)? for Type ( where Bounds )?`");
+    };
+}
+
+/// For a type `T: Copy`, implement unary operator `unop` as `unop T` with the given body, as well as `unop &T` using [`forward_ref_unop`].
+///
+/// For readability, the expected syntax of the macro is the following:
+/// ```text
+/// ( #[cfg(Predicate)] )*
+/// ( [ Generics ] )?
+/// impl Trait(, Method)? ( -> Output )? for Type { |arg| Body }
+/// ( where Bounds )?
+/// ```
+/// - `Predicate`s are [`cfg`](https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute) predicates every generated implementation is gated by
+/// - `Generics` are comma-seperated type or const generics
+/// - `Trait` is the trait to be implemented, which has to define an associated type `Output`
+/// - `Method` is the method that `Trait` defines, taking `self` and returning `Self::Output`\
+///   (can be ommitted for [`Neg`](https://doc.rust-lang.org/std/ops/trait.Neg.html) and [`Not`](https://doc.rust-lang.org/std/ops/trait.Not.html))
+/// - `Output` is the output type of the operation\
+///   if no `Output` is given, `Output` = `Type` is assumed
+/// - `Type` is the type that `Trait` is implemented on (i.e. `T`)
+/// - `arg` is a pattern the operand is bound to in `Body`
+/// - `Body` is an expression of type `Output` computing the result of the operation
+/// - `Bounds` are comma-seperated trait bounds for the listed generics
+///
+/// # Example
+///
+/// ```
+/// use forward_ref_generic::impl_unop;
+///
+/// trait Conj {
+///     type Output;
+///
+///     fn conj(self) -> Self::Output;
+/// }
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Complex {
+///     re: f64,
+///     im: f64,
+/// }
+///
+/// impl_unop! {
+///     impl Conj, conj -> Complex for Complex { |c| Complex { re: c.re, im: -c.im } }
+/// }
+///
+/// let c = Complex { re: 1.0, im: 2.0 };
+/// assert_eq!((&c).conj(), Complex { re: 1.0, im: -2.0 });
+/// ```
+#[macro_export]
+macro_rules! impl_unop {
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident -> $($rest:tt)*
+    ) => {
+        $crate::impl_unop! {
+            @output
+            { $( #[cfg $cfg] )* $( [ $($generic)* ] )? impl $impl, $meth }
+            []
+            $($rest)*
+        }
+    };
+
+    // if no Output was given, assume Output = Type
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident for $type:ty { $($body:tt)* }
+        $( where $($bound:tt)* )?
+    ) => {
+        $crate::impl_unop! {
+            @emit
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth -> [ $type ] for $type { $($body)* }
+            $( where $($bound)* )?
+        }
+    };
+
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident -> $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_method! {
+            unary impl_unop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { -> $($rest)* }
+        }
+    };
+    (
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident for $($rest:tt)*
+    ) => {
+        $crate::__forward_ref_method! {
+            unary impl_unop { $( #[cfg $cfg] )* $( [ $($generic)* ] )? }
+            $impl
+            { for $($rest)* }
+        }
+    };
+
+    // collects the output type token by token,
+    // as a type cannot be followed by `for` in a macro pattern
+    (
+        @output { $($pre:tt)* } [ $($output:tt)* ]
+        for $($rest:tt)*
+    ) => {
+        $crate::impl_unop! { @emit $($pre)* -> [ $($output)* ] for $($rest)* }
+    };
+    (
+        @output { $($pre:tt)* } [ $($output:tt)* ]
+        $next:tt $($rest:tt)*
+    ) => {
+        $crate::impl_unop! { @output { $($pre)* } [ $($output)* $next ] $($rest)* }
+    };
+
+    (
+        @emit
+        $( #[cfg $cfg:tt] )*
+        $( [ $($generic:tt)* ] )?
+        impl $impl:ident, $meth:ident -> [ $output:ty ] for $type:ty
+        { |$arg:pat_param| $body:expr }
+        $( where $($bound:tt)* )?
+    ) => {
+        $( #[cfg $cfg] )*
+        impl$(<$($generic)*>)? $impl for $type
+        $(where
+            $($bound)*)?
+        {
+            type Output = $output;
+
+            fn $meth(self) -> Self::Output {
+                let $arg = self;
+                $body
+            }
+        }
+
+        $crate::forward_ref_unop! {
+            $( #[cfg $cfg] )*
+            $( [ $($generic)* ] )?
+            impl $impl, $meth for $type
+            $( where $($bound)* )?
+        }
+    };
 }
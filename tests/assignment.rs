@@ -0,0 +1,337 @@
+use forward_ref_generic::forward_ref_op_assign;
+use std::ops::AddAssign;
+
+mod simple_generic {
+    use super::{forward_ref_op_assign, AddAssign};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> AddAssign for Point<T>
+    where
+        T: Copy + AddAssign,
+    {
+        fn add_assign(&mut self, rhs: Self) {
+            self.x += rhs.x;
+            self.y += rhs.y;
+        }
+    }
+
+    forward_ref_op_assign! {
+        [T]
+        impl AddAssign for Point<T>
+        where T: Copy + AddAssign
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut p1 = Point { x: 1, y: 2 };
+        let mut p2 = Point { x: 1, y: 2 };
+        let p3 = Point { x: 5, y: 3 };
+
+        p1 += p3;
+        p2 += &p3;
+        assert_eq!(p1, p2);
+    }
+}
+
+mod const_generics {
+    use super::{forward_ref_op_assign, AddAssign};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Array<T, const N: usize> {
+        arr: [T; N],
+    }
+
+    impl<T, const N: usize> AddAssign for Array<T, N>
+    where
+        T: Copy + AddAssign,
+    {
+        fn add_assign(&mut self, rhs: Self) {
+            for (val, rhs) in self.arr.iter_mut().zip(rhs.arr) {
+                *val += rhs;
+            }
+        }
+    }
+
+    forward_ref_op_assign! {
+        [T, const N: usize]
+        impl AddAssign for Array<T, N>
+        where T: Copy + AddAssign
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut a1 = Array { arr: [1, 2, 3] };
+        let mut a2 = Array { arr: [1, 2, 3] };
+        let a3 = Array { arr: [3, 2, 5] };
+
+        a1 += a3;
+        a2 += &a3;
+        assert_eq!(a1, Array { arr: [4, 4, 8] });
+        assert_eq!(a1, a2);
+    }
+}
+
+mod all_operators {
+    use super::forward_ref_op_assign;
+    use std::ops::{
+        AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, DivAssign, MulAssign, RemAssign,
+        ShlAssign, ShrAssign, SubAssign,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    // implements `LHS op= RHS` for every listed operator
+    macro_rules! impl_ops {
+        ($lhs:ident, $rhs:ident: $($impl:ident, $meth:ident, $op:tt;)*) => {
+            $(
+                impl $impl<$rhs> for $lhs {
+                    fn $meth(&mut self, rhs: $rhs) {
+                        self.0 $op rhs.0;
+                    }
+                }
+
+                forward_ref_op_assign! {
+                    impl $impl for $lhs, $rhs
+                }
+            )*
+        };
+    }
+
+    impl_ops! { Int, Int:
+        AddAssign, add_assign, +=; SubAssign, sub_assign, -=; MulAssign, mul_assign, *=;
+        DivAssign, div_assign, /=; RemAssign, rem_assign, %=; BitAndAssign, bitand_assign, &=;
+        BitOrAssign, bitor_assign, |=; BitXorAssign, bitxor_assign, ^=;
+        ShlAssign, shl_assign, <<=; ShrAssign, shr_assign, >>=;
+    }
+
+    #[test]
+    fn forward_ref_op_assign() {
+        macro_rules! assert_forwarded {
+            ($lhs:expr, $rhs:expr; $($op:tt)*) => {
+                $(
+                    let mut owned = $lhs;
+                    let mut borrowed = $lhs;
+                    owned $op $rhs;
+                    borrowed $op &$rhs;
+                    assert_eq!(owned, borrowed);
+                )*
+            };
+        }
+
+        assert_forwarded!(Int(12), Int(5); += -= *= /= %= &= |= ^= <<= >>=);
+    }
+}
+
+mod arith_assign {
+    use forward_ref_generic::forward_ref_arith_assign;
+    use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    // implements `Point<T> op= Point<T>` componentwise for every listed operator
+    macro_rules! impl_ops {
+        ($($impl:ident, $meth:ident, $op:tt;)*) => {
+            $(
+                impl<T> $impl for Point<T>
+                where
+                    T: Copy + $impl,
+                {
+                    fn $meth(&mut self, rhs: Self) {
+                        self.x $op rhs.x;
+                        self.y $op rhs.y;
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_ops! {
+        AddAssign, add_assign, +=; SubAssign, sub_assign, -=;
+        MulAssign, mul_assign, *=; DivAssign, div_assign, /=;
+    }
+
+    forward_ref_arith_assign! {
+        [T]
+        impl for Point<T>
+        where T: Copy + AddAssign + SubAssign + MulAssign + DivAssign
+    }
+
+    #[test]
+    fn forward_ref_arith_assign() {
+        let mut p = Point { x: 12, y: 9 };
+        let q = Point { x: 3, y: 2 };
+
+        p += &q;
+        assert_eq!(p, Point { x: 15, y: 11 });
+        p -= &q;
+        assert_eq!(p, Point { x: 12, y: 9 });
+        p *= &q;
+        assert_eq!(p, Point { x: 36, y: 18 });
+        p /= &q;
+        assert_eq!(p, Point { x: 12, y: 9 });
+    }
+
+    mod not_in_scope {
+        use forward_ref_generic::forward_ref_arith_assign;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Int(i32);
+
+        // the traits are only named by their full paths, so the macro has to bring them into scope itself
+        macro_rules! impl_ops {
+            ($($impl:ident, $meth:ident, $op:tt;)*) => {
+                $(
+                    impl std::ops::$impl for Int {
+                        fn $meth(&mut self, rhs: Self) {
+                            self.0 $op rhs.0;
+                        }
+                    }
+                )*
+            };
+        }
+
+        impl_ops! {
+            AddAssign, add_assign, +=; SubAssign, sub_assign, -=;
+            MulAssign, mul_assign, *=; DivAssign, div_assign, /=;
+        }
+
+        forward_ref_arith_assign! {
+            impl for Int
+        }
+
+        #[test]
+        fn forward_ref_arith_assign() {
+            let mut i = Int(6);
+
+            i += &Int(3);
+            assert_eq!(i, Int(9));
+            i -= &Int(3);
+            assert_eq!(i, Int(6));
+            i *= &Int(3);
+            assert_eq!(i, Int(18));
+            i /= &Int(3);
+            assert_eq!(i, Int(6));
+        }
+    }
+}
+
+mod local_types {
+    #[test]
+    fn add_assign() {
+        use std::ops::AddAssign;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Int(i32);
+
+        impl AddAssign for Int {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        // the macro is deliberately invoked by path without being imported
+        forward_ref_generic::forward_ref_op_assign! {
+            impl AddAssign for Int
+        }
+
+        let mut i = Int(1);
+        i += &Int(2);
+        assert_eq!(i, Int(3));
+    }
+}
+
+mod shift_assign_from_shift {
+    use forward_ref_generic::derive_shift_assign_from_shift;
+    // `ShlAssign` and `ShrAssign` are deliberately not imported, as they are only named in the macro invocations
+    use std::ops::{Shl, Shr};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Reg(u16);
+
+    impl Shl<u32> for Reg {
+        type Output = Self;
+
+        fn shl(self, rhs: u32) -> Self::Output {
+            Self(self.0 << rhs)
+        }
+    }
+
+    impl Shr<u32> for Reg {
+        type Output = Self;
+
+        fn shr(self, rhs: u32) -> Self::Output {
+            Self(self.0 >> rhs)
+        }
+    }
+
+    derive_shift_assign_from_shift! {
+        impl ShlAssign for Reg, u32
+    }
+
+    derive_shift_assign_from_shift! {
+        impl ShrAssign for Reg, u32
+        ; forward_ref
+    }
+
+    #[test]
+    fn shl_assign() {
+        let mut reg = Reg(0b0011);
+
+        reg <<= 2u32;
+        assert_eq!(reg, Reg(0b1100));
+    }
+
+    #[test]
+    fn shr_assign() {
+        let mut reg = Reg(0b1100);
+
+        reg >>= 2u32;
+        assert_eq!(reg, Reg(0b0011));
+        reg >>= &1u32;
+        assert_eq!(reg, Reg(0b0001));
+    }
+}
+
+mod type_alias {
+    use super::{forward_ref_op_assign, AddAssign};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T, const N: usize>([T; N]);
+
+    type Vec3 = Vector<f64, 3>;
+
+    impl AddAssign for Vec3 {
+        fn add_assign(&mut self, rhs: Self) {
+            for (x, a) in self.0.iter_mut().zip(rhs.0) {
+                *x += a;
+            }
+        }
+    }
+
+    forward_ref_op_assign! {
+        impl AddAssign for Vec3
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut v1: Vec3 = Vector([1.0, 2.0, 3.0]);
+        let mut v2 = v1;
+        let v3: Vec3 = Vector([0.5, 0.5, 0.5]);
+
+        v1 += v3;
+        v2 += &v3;
+
+        assert_eq!(v1, Vector([1.5, 2.5, 3.5]));
+        assert_eq!(v1, v2);
+    }
+}
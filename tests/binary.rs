@@ -1,5 +1,9 @@
-#[allow(clippy::op_ref)]
-use forward_ref_generic::{commutative_binop, forward_ref_binop, forward_ref_commutative_binop};
+#![allow(clippy::op_ref)]
+
+use forward_ref_generic::{
+    commutative_binop, forward_ref_binop, forward_ref_binop_clone, forward_ref_binop_debug,
+    forward_ref_binop_refs, forward_ref_commutative_binop,
+};
 use std::ops::Add;
 
 mod no_generic {
@@ -78,33 +82,31 @@ mod simple_generic {
 }
 
 mod complicated_generics {
-    use super::{forward_ref_binop, Add};
+    use forward_ref_generic::componentwise_binop;
+    use std::ops::{Add, Mul, Sub};
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     struct Array<T, const M: usize> {
         arr: [T; M],
     }
 
-    impl<T, const M: usize> Add for Array<T, M>
-    where
-        T: Copy + Add<Output = T>,
-    {
-        type Output = Self;
+    // [x1, x2, x3] + [y1, y2, y3] = [x1 + y1, x2 + y2, x3 + y3]
+    componentwise_binop! {
+        [T, const M: usize]
+        impl Add for Array<T, M>; field = arr
+        where T: Copy + Add<Output = T>
+    }
 
-        // [x1, x2, x3] + [y1, y2, y3] = [x1 + y1, x2 + y2, x3 + y3]
-        fn add(self, rhs: Self) -> Self::Output {
-            let mut result = self.arr; // this is a copy
-            for (i, val) in result.iter_mut().enumerate() {
-                *val = *val + rhs.arr[i];
-            }
-            Self { arr: result }
-        }
+    componentwise_binop! {
+        [T, const M: usize]
+        impl Sub for Array<T, M>; field = arr
+        where T: Copy + Sub<Output = T>
     }
 
-    forward_ref_binop! {
+    componentwise_binop! {
         [T, const M: usize]
-        impl Add for Array<T, M>
-        where T: Copy + Add<Output = T>
+        impl Mul for Array<T, M>; field = arr
+        where T: Copy + Mul<Output = T>
     }
 
     #[test]
@@ -112,10 +114,33 @@ mod complicated_generics {
         let p1 = Array { arr: [1, 2, 3] };
         let p2 = Array { arr: [3, 2, 5] };
 
+        assert_eq!(p1 + p2, Array { arr: [4, 4, 8] });
         assert_eq!(p1 + p2, p1 + &p2);
         assert_eq!(p1 + p2, &p1 + p2);
         assert_eq!(p1 + p2, &p1 + &p2);
     }
+
+    #[test]
+    fn sub() {
+        let p1 = Array { arr: [1, 2, 3] };
+        let p2 = Array { arr: [3, 2, 5] };
+
+        assert_eq!(p1 - p2, Array { arr: [-2, 0, -2] });
+        assert_eq!(p1 - p2, p1 - &p2);
+        assert_eq!(p1 - p2, &p1 - p2);
+        assert_eq!(p1 - p2, &p1 - &p2);
+    }
+
+    #[test]
+    fn mul() {
+        let p1 = Array { arr: [1, 2, 3] };
+        let p2 = Array { arr: [3, 2, 5] };
+
+        assert_eq!(p1 * p2, Array { arr: [3, 4, 15] });
+        assert_eq!(p1 * p2, p1 * &p2);
+        assert_eq!(p1 * p2, &p1 * p2);
+        assert_eq!(p1 * p2, &p1 * &p2);
+    }
 }
 
 mod commutative {
@@ -143,6 +168,19 @@ mod commutative {
         impl Add for Int1, Int2
     }
 
+    // expands to nothing as `Int1 + Int1` is the original operation itself
+    commutative_binop! {
+        impl Add for Int1, Int1; allow_same
+    }
+
+    impl Add for Int1 {
+        type Output = i32;
+
+        fn add(self, rhs: Int1) -> Self::Output {
+            self.0 + rhs.0
+        }
+    }
+
     #[test]
     fn add_commutative_only() {
         let int1 = Int1(5);
@@ -152,6 +190,11 @@ mod commutative {
         assert_eq!(int2 + int1, 3 + 5);
     }
 
+    #[test]
+    fn add_allow_same() {
+        assert_eq!(Int1(5) + Int1(3), 5 + 3);
+    }
+
     #[test]
     fn add_forward_ref_commutative() {
         let int1 = Int1(5);
@@ -169,3 +212,1898 @@ mod commutative {
         assert_eq!(&int2 + &int1, 3 + 5);
     }
 }
+
+mod impl_commutative {
+    use forward_ref_generic::impl_commutative_binop;
+    use std::ops::{Add, Mul};
+
+    #[derive(Clone, Copy, PartialEq)]
+    struct Int1(i32);
+
+    #[derive(Clone, Copy, PartialEq)]
+    struct Int2(i32);
+
+    impl_commutative_binop! {
+        impl Add for Int1, Int2 { |a: Int1, b: Int2| -> i32 { a.0 + b.0 } }
+    }
+
+    impl_commutative_binop! {
+        impl Mul for Int1, Int2 { |a, b| -> i32 { a.0 * b.0 } }
+        ; forward_ref
+    }
+
+    #[test]
+    fn add() {
+        let int1 = Int1(5);
+        let int2 = Int2(3);
+
+        assert_eq!(int1 + int2, 5 + 3);
+        assert_eq!(int2 + int1, 3 + 5);
+    }
+
+    #[test]
+    fn mul_forward_ref() {
+        let int1 = Int1(5);
+        let int2 = Int2(3);
+
+        assert_eq!(int1 * int2, 5 * 3);
+        assert_eq!(int2 * int1, 3 * 5);
+
+        assert_eq!(&int1 * int2, 5 * 3);
+        assert_eq!(int1 * &int2, 5 * 3);
+        assert_eq!(&int1 * &int2, 5 * 3);
+
+        assert_eq!(&int2 * int1, 3 * 5);
+        assert_eq!(int2 * &int1, 3 * 5);
+        assert_eq!(&int2 * &int1, 3 * 5);
+    }
+}
+
+mod rhs_only_generics {
+    // `L` only appears in the RHS type, which must not cause any warnings
+    #![deny(warnings)]
+
+    use super::{forward_ref_binop, Add};
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Matrix<T, const M: usize, const N: usize> {
+        m: [[T; N]; M],
+    }
+
+    impl<T, const M: usize, const N: usize, const L: usize> Mul<Matrix<T, N, L>> for Matrix<T, M, N>
+    where
+        T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+    {
+        type Output = Matrix<T, M, L>;
+
+        fn mul(self, rhs: Matrix<T, N, L>) -> Self::Output {
+            let mut result = [[T::default(); L]; M];
+            for (i, row) in result.iter_mut().enumerate() {
+                for (j, val) in row.iter_mut().enumerate() {
+                    for k in 0..N {
+                        *val = *val + self.m[i][k] * rhs.m[k][j];
+                    }
+                }
+            }
+            Matrix { m: result }
+        }
+    }
+
+    forward_ref_binop! {
+        [T, const M: usize, const N: usize, const L: usize]
+        impl Mul for Matrix<T, M, N>, Matrix<T, N, L>
+        where T: Copy + Default + Add<Output = T> + Mul<Output = T>
+    }
+
+    #[test]
+    fn mul() {
+        let m1 = Matrix {
+            m: [[1, 2, 2], [2, 1, 2]],
+        };
+        let m2 = Matrix {
+            m: [[0, 1], [1, 1], [2, 1]],
+        };
+
+        assert_eq!(m1 * m2, Matrix { m: [[6, 5], [5, 5]] });
+        assert_eq!(m1 * m2, m1 * &m2);
+        assert_eq!(m1 * m2, &m1 * m2);
+        assert_eq!(m1 * m2, &m1 * &m2);
+    }
+}
+
+mod projection_bounds {
+    use super::{forward_ref_binop, Add};
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Add for Point<T>
+    where
+        T: Copy + Add<Output = T> + Mul,
+        <T as Mul>::Output: Copy,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Point<T>
+        where T: Copy + Add<Output = T> + Mul, <T as Mul>::Output: Copy
+    }
+
+    #[test]
+    fn add() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+
+        assert_eq!(p1 + p2, p1 + &p2);
+        assert_eq!(p1 + p2, &p1 + p2);
+        assert_eq!(p1 + p2, &p1 + &p2);
+    }
+}
+
+mod const_expression_generics {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Array<T, const M: usize> {
+        arr: [T; M],
+    }
+
+    // concatenation
+    impl<T> Add<Array<T, { 1 + 1 }>> for Array<T, { 2 - 1 }>
+    where
+        T: Copy + Default,
+    {
+        type Output = Array<T, { 1 + 2 }>;
+
+        fn add(self, rhs: Array<T, { 1 + 1 }>) -> Self::Output {
+            let mut result = [T::default(); 1 + 2];
+            result[..1].copy_from_slice(&self.arr);
+            result[1..].copy_from_slice(&rhs.arr);
+            Array { arr: result }
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Array<T, { 2 - 1 }>, Array<T, { 1 + 1 }>
+        where T: Copy + Default
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Wrapper<const M: usize>(Array<i32, M>);
+
+    impl<const M: usize> Add for Wrapper<{ M }> {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut result = self.0.arr;
+            for (i, val) in result.iter_mut().enumerate() {
+                *val += rhs.0.arr[i];
+            }
+            Self(Array { arr: result })
+        }
+    }
+
+    forward_ref_binop! {
+        [const M: usize]
+        impl Add for Wrapper<{ M }>
+    }
+
+    #[test]
+    fn add_concat() {
+        let a1 = Array { arr: [1] };
+        let a2 = Array { arr: [2, 3] };
+
+        assert_eq!(a1 + a2, Array { arr: [1, 2, 3] });
+        assert_eq!(a1 + a2, a1 + &a2);
+        assert_eq!(a1 + a2, &a1 + a2);
+        assert_eq!(a1 + a2, &a1 + &a2);
+    }
+
+    #[test]
+    fn add_braced_param() {
+        let w1 = Wrapper(Array { arr: [1, 2, 3] });
+        let w2 = Wrapper(Array { arr: [3, 2, 5] });
+
+        assert_eq!(w1 + w2, w1 + &w2);
+        assert_eq!(w1 + w2, &w1 + w2);
+        assert_eq!(w1 + w2, &w1 + &w2);
+    }
+}
+
+mod clone {
+    use super::{forward_ref_binop_clone, Add};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // counts how often a value has been cloned
+    #[derive(Debug)]
+    struct Counted {
+        val: i32,
+        clones: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Self {
+                val: self.val,
+                clones: Rc::clone(&self.clones),
+            }
+        }
+    }
+
+    impl Add for Counted {
+        type Output = i32;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            self.val + rhs.val
+        }
+    }
+
+    forward_ref_binop_clone! {
+        impl Add for Counted
+    }
+
+    fn counted(val: i32, clones: &Rc<Cell<usize>>) -> Counted {
+        Counted {
+            val,
+            clones: Rc::clone(clones),
+        }
+    }
+
+    #[test]
+    fn add() {
+        let lhs_clones = Rc::new(Cell::new(0));
+        let rhs_clones = Rc::new(Cell::new(0));
+
+        assert_eq!(counted(5, &lhs_clones) + &counted(3, &rhs_clones), 8);
+        assert_eq!((lhs_clones.get(), rhs_clones.get()), (0, 1));
+
+        assert_eq!(&counted(5, &lhs_clones) + counted(3, &rhs_clones), 8);
+        assert_eq!((lhs_clones.get(), rhs_clones.get()), (1, 1));
+
+        assert_eq!(&counted(5, &lhs_clones) + &counted(3, &rhs_clones), 8);
+        assert_eq!((lhs_clones.get(), rhs_clones.get()), (2, 2));
+    }
+}
+
+mod debug {
+    use super::forward_ref_binop_debug;
+
+    #[test]
+    fn expansion() {
+        let expansion = forward_ref_binop_debug! {
+            [T]
+            impl Add for Point<T>
+            where T: Copy + Add<Output = T>
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert!(expansion.contains("impl<T>Add<Point<T>>for&Point<T>whereT:Copy+Add<Output=T>"));
+        assert!(expansion.contains("impl<T>Add<&Point<T>>forPoint<T>whereT:Copy+Add<Output=T>"));
+        assert!(expansion.contains("impl<T>Add<&Point<T>>for&Point<T>whereT:Copy+Add<Output=T>"));
+        assert!(expansion.contains("fnadd(self,rhs:&Point<T>)->Self::Output"));
+    }
+
+    #[test]
+    fn depth2() {
+        let expansion = forward_ref_binop_debug! {
+            impl Add for Int, Int; depth(2)
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert_eq!(expansion.matches("implAdd<").count(), 4);
+        assert!(expansion.contains("implAdd<&&Int>for&&Int"));
+        assert!(expansion.contains("fnadd(self,rhs:&&Int)->Self::Output"));
+    }
+
+    #[test]
+    fn split_generics() {
+        let expansion = forward_ref_binop_debug! {
+            [T] [U]
+            impl Add for Point<T>, Point<U>
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert!(expansion.contains("impl<T,U>Add<Point<U>>for&Point<T>"));
+    }
+
+    #[test]
+    fn shared_generics() {
+        let expansion = forward_ref_binop_debug! {
+            [T, const N: usize][T, const N: usize, const L: usize]
+            impl Mul for Matrix<T, N>, Matrix<T, L>
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert!(expansion.contains("impl<T,constN:usize,constL:usize>Mul<Matrix<T,L>>for&Matrix<T,N>"));
+    }
+
+    #[test]
+    fn empty_lhs_generics() {
+        let expansion = forward_ref_binop_debug! {
+            [][U]
+            impl Add for Scalar, Point<U>
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert!(expansion.contains("impl<U>Add<Point<U>>for&Scalar"));
+    }
+}
+
+mod method_attributes {
+    use super::{forward_ref_binop, forward_ref_binop_debug, Add};
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    impl Add for Int {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Int {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            Self(self.0 * rhs.0)
+        }
+    }
+
+    forward_ref_binop! {
+        #[cfg(all())]
+        #[cfg_attr(all(), inline(always))]
+        impl Add for Int
+    }
+
+    // the attribute does not exist, so this only compiles if it is never applied
+    forward_ref_binop! {
+        #[cfg_attr(any(), no_such_attribute)]
+        impl Mul for Int
+    }
+
+    #[test]
+    fn cfg_attr() {
+        assert_eq!(Int(1) + Int(2), &Int(1) + &Int(2));
+        assert_eq!(Int(2) * Int(3), &Int(2) * &Int(3));
+    }
+
+    #[test]
+    fn expansion() {
+        let expansion = forward_ref_binop_debug! {
+            #[cfg(all())]
+            #[cfg_attr(feature = "fast", inline(always))]
+            impl Add for Int
+        };
+        let expansion: String = expansion.split_whitespace().collect();
+
+        assert_eq!(expansion.matches("#[cfg(all())]impl").count(), 3);
+        assert_eq!(
+            expansion.matches("#[cfg_attr(feature=\"fast\",inline(always))]fnadd").count(),
+            3
+        );
+    }
+}
+
+mod all_operators {
+    use forward_ref_generic::{
+        commutative_binop, forward_ref_binop, forward_ref_binop_clone, forward_ref_binop_debug,
+        forward_ref_binop_refs, forward_ref_commutative_binop,
+    };
+    use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct CloneInt(i32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct RefInt(i32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int1(i32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int2(i32);
+
+    // implements `LHS op RHS` for every listed operator
+    macro_rules! impl_ops {
+        ($lhs:ident, $rhs:ident: $($impl:ident, $meth:ident, $op:tt;)*) => {
+            $(
+                impl $impl<$rhs> for $lhs {
+                    type Output = i32;
+
+                    fn $meth(self, rhs: $rhs) -> Self::Output {
+                        self.0 $op rhs.0
+                    }
+                }
+            )*
+        };
+    }
+
+    // invokes `Macro` for every binary operator in `std::ops`
+    macro_rules! for_all_operators {
+        ($mac:ident for $($ty:ty),+) => {
+            $mac! { impl Add for $($ty),+ }
+            $mac! { impl Sub for $($ty),+ }
+            $mac! { impl Mul for $($ty),+ }
+            $mac! { impl Div for $($ty),+ }
+            $mac! { impl Rem for $($ty),+ }
+            $mac! { impl BitAnd for $($ty),+ }
+            $mac! { impl BitOr for $($ty),+ }
+            $mac! { impl BitXor for $($ty),+ }
+            $mac! { impl Shl for $($ty),+ }
+            $mac! { impl Shr for $($ty),+ }
+        };
+    }
+
+    impl_ops! { Int, Int:
+        Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; Rem, rem, %;
+        BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^; Shl, shl, <<; Shr, shr, >>;
+    }
+    for_all_operators!(forward_ref_binop for Int);
+
+    impl_ops! { CloneInt, CloneInt:
+        Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; Rem, rem, %;
+        BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^; Shl, shl, <<; Shr, shr, >>;
+    }
+    for_all_operators!(forward_ref_binop_clone for CloneInt);
+
+    impl_ops! { RefInt, RefInt:
+        Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; Rem, rem, %;
+        BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^; Shl, shl, <<; Shr, shr, >>;
+    }
+    for_all_operators!(forward_ref_binop_refs for RefInt);
+
+    impl_ops! { Int1, Int2:
+        Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; Rem, rem, %;
+        BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^; Shl, shl, <<; Shr, shr, >>;
+    }
+    for_all_operators!(commutative_binop for Int1, Int2);
+    for_all_operators!(forward_ref_commutative_binop for Int1, Int2);
+
+    macro_rules! assert_forwarded {
+        ($lhs:expr, $rhs:expr; $($op:tt)*) => {
+            $(
+                assert_eq!($lhs $op $rhs, $lhs $op &$rhs);
+                assert_eq!($lhs $op $rhs, &$lhs $op $rhs);
+                assert_eq!($lhs $op $rhs, &$lhs $op &$rhs);
+            )*
+        };
+    }
+
+    #[test]
+    fn forward_ref_binop() {
+        assert_forwarded!(Int(12), Int(5); + - * / % & | ^ << >>);
+    }
+
+    #[test]
+    fn forward_ref_binop_clone() {
+        assert_forwarded!(CloneInt(12), CloneInt(5); + - * / % & | ^ << >>);
+    }
+
+    #[test]
+    fn forward_ref_binop_refs() {
+        macro_rules! assert_refs_forwarded {
+            ($lhs:expr, $rhs:expr; $($op:tt)*) => {
+                $(
+                    assert_eq!($lhs $op $rhs, &$lhs $op &$rhs);
+                )*
+            };
+        }
+
+        assert_refs_forwarded!(RefInt(12), RefInt(5); + - * / % & | ^ << >>);
+    }
+
+    #[test]
+    fn commutative_binop() {
+        assert_forwarded!(Int1(12), Int2(5); + - * / % & | ^ << >>);
+        assert_forwarded!(Int2(5), Int1(12); + - * / % & | ^ << >>);
+    }
+
+    #[test]
+    fn forward_ref_binop_debug() {
+        macro_rules! assert_method {
+            ($($impl:ident, $meth:literal;)*) => {
+                $(
+                    let expansion: String = forward_ref_binop_debug! { impl $impl for Int }
+                        .split_whitespace()
+                        .collect();
+                    assert!(expansion.contains(concat!("fn", $meth, "(self,")));
+                )*
+            };
+        }
+
+        assert_method! {
+            Add, "add"; Sub, "sub"; Mul, "mul"; Div, "div"; Rem, "rem";
+            BitAnd, "bitand"; BitOr, "bitor"; BitXor, "bitxor"; Shl, "shl"; Shr, "shr";
+        }
+    }
+}
+
+mod split_generics {
+    use super::{forward_ref_binop, Add};
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Matrix<T, const M: usize, const N: usize> {
+        m: [[T; N]; M],
+    }
+
+    impl<T, const M: usize, const N: usize, const L: usize> Mul<Matrix<T, N, L>> for Matrix<T, M, N>
+    where
+        T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+    {
+        type Output = Matrix<T, M, L>;
+
+        fn mul(self, rhs: Matrix<T, N, L>) -> Self::Output {
+            let mut result = [[T::default(); L]; M];
+            for (i, row) in result.iter_mut().enumerate() {
+                for (j, val) in row.iter_mut().enumerate() {
+                    for k in 0..N {
+                        *val = *val + self.m[i][k] * rhs.m[k][j];
+                    }
+                }
+            }
+            Matrix { m: result }
+        }
+    }
+
+    // `T` and `N` are used by both sides, so they are listed in both brackets
+    forward_ref_binop! {
+        [T, const M: usize, const N: usize][T, const N: usize, const L: usize]
+        impl Mul for Matrix<T, M, N>, Matrix<T, N, L>
+        where T: Copy + Default + Add<Output = T> + Mul<Output = T>
+    }
+
+    #[test]
+    fn mul() {
+        let m1 = Matrix {
+            m: [[1, 2, 2], [2, 1, 2]],
+        };
+        let m2 = Matrix {
+            m: [[0, 1], [1, 1], [2, 1]],
+        };
+
+        assert_eq!(m1 * m2, Matrix { m: [[6, 5], [5, 5]] });
+        assert_eq!(m1 * m2, m1 * &m2);
+        assert_eq!(m1 * m2, &m1 * m2);
+        assert_eq!(m1 * m2, &m1 * &m2);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Len<const N: usize>;
+
+    impl<const N: usize, const L: usize> Add<Len<L>> for Len<N> {
+        type Output = usize;
+
+        fn add(self, _rhs: Len<L>) -> Self::Output {
+            N + L
+        }
+    }
+
+    forward_ref_binop! {
+        [const N: usize,] [const L: usize]
+        impl Add for Len<N>, Len<L>
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_eq!(Len::<1> + &Len::<2>, 3);
+        assert_eq!(&Len::<1> + Len::<2>, 3);
+        assert_eq!(&Len::<1> + &Len::<2>, 3);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Scalar;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Wrapper<U>(U);
+
+    impl<U> Add<Wrapper<U>> for Scalar {
+        type Output = U;
+
+        fn add(self, rhs: Wrapper<U>) -> Self::Output {
+            rhs.0
+        }
+    }
+
+    forward_ref_binop! {
+        [][U]
+        impl Add for Scalar, Wrapper<U>
+        where U: Copy
+    }
+
+    #[test]
+    fn empty_lhs_generics() {
+        assert_eq!(Scalar + &Wrapper(1), 1);
+        assert_eq!(&Scalar + Wrapper(1), 1);
+        assert_eq!(&Scalar + &Wrapper(1), 1);
+    }
+}
+
+mod sub_from_add_neg {
+    use forward_ref_generic::{derive_sub_from_add_neg, forward_ref_binop, forward_ref_unop};
+    // `Sub` is deliberately not imported, as it is only named in the macro invocation
+    use std::ops::{Add, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Add for Point<T>
+    where
+        T: Copy + Add<Output = T> + Neg<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    impl<T> Neg for Point<T>
+    where
+        T: Copy + Add<Output = T> + Neg<Output = T>,
+    {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self {
+                x: -self.x,
+                y: -self.y,
+            }
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Point<T>
+        where T: Copy + Add<Output = T> + Neg<Output = T>
+    }
+
+    forward_ref_unop! {
+        [T]
+        impl Neg for Point<T>
+        where T: Copy + Add<Output = T> + Neg<Output = T>
+    }
+
+    derive_sub_from_add_neg! {
+        [T]
+        impl Sub for Point<T>; forward_ref
+        where T: Copy + Add<Output = T> + Neg<Output = T>
+    }
+
+    #[test]
+    fn sub() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+
+        assert_eq!(p1 - p2, Point { x: -4, y: -1 });
+        assert_eq!(p1 - p2, p1 + (-p2));
+        assert_eq!(p1 - p2, p1 - &p2);
+        assert_eq!(p1 - p2, &p1 - p2);
+        assert_eq!(p1 - p2, &p1 - &p2);
+        assert_eq!(&p1 - &p2, &p1 + -&p2);
+    }
+}
+
+mod scalar_mul {
+    use forward_ref_generic::commutative_scalar_mul;
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Mul<T> for Vector<T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        type Output = Self;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            Self {
+                x: self.x * rhs,
+                y: self.y * rhs,
+            }
+        }
+    }
+
+    commutative_scalar_mul! {
+        [T]
+        impl Mul for Vector<T>, scalars [f64, i32]
+        where T: Copy + Mul<Output = T>
+    }
+
+    #[test]
+    fn mul_f64() {
+        let v = Vector { x: 1.5, y: -2.0 };
+        let s = 2.0;
+
+        assert_eq!(v * s, Vector { x: 3.0, y: -4.0 });
+        assert_eq!(v * s, s * v);
+        assert_eq!(v * s, v * &s);
+        assert_eq!(v * s, &v * s);
+        assert_eq!(v * s, &v * &s);
+        assert_eq!(v * s, s * &v);
+        assert_eq!(v * s, &s * v);
+        assert_eq!(v * s, &s * &v);
+    }
+
+    #[test]
+    fn mul_i32() {
+        let v = Vector { x: 1, y: -2 };
+        let s = 3;
+
+        assert_eq!(v * s, Vector { x: 3, y: -6 });
+        assert_eq!(v * s, s * v);
+        assert_eq!(v * s, v * &s);
+        assert_eq!(v * s, &v * s);
+        assert_eq!(v * s, &v * &s);
+        assert_eq!(v * s, s * &v);
+        assert_eq!(v * s, &s * v);
+        assert_eq!(v * s, &s * &v);
+    }
+}
+
+mod cfg {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Add for Point {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    // exactly one of these is enabled,
+    // they'd be conflicting implementations otherwise
+    forward_ref_binop! {
+        #[cfg(target_pointer_width = "64")]
+        impl Add for Point
+    }
+
+    forward_ref_binop! {
+        #[cfg(not(target_pointer_width = "64"))]
+        #[cfg(all())]
+        impl Add for Point
+    }
+
+    #[test]
+    fn add() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+
+        assert_eq!(p1 + p2, p1 + &p2);
+        assert_eq!(p1 + p2, &p1 + p2);
+        assert_eq!(p1 + p2, &p1 + &p2);
+    }
+}
+
+mod shift {
+    use forward_ref_generic::forward_ref_shift;
+    use std::ops::{Shl, Shr};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct BitBuffer(u64);
+
+    // implements `BitBuffer << I` and `BitBuffer >> I` for every listed integer type
+    macro_rules! impl_shifts {
+        ($($int:ty)*) => {
+            $(
+                impl Shl<$int> for BitBuffer {
+                    type Output = Self;
+
+                    fn shl(self, rhs: $int) -> Self::Output {
+                        Self(self.0 << rhs)
+                    }
+                }
+
+                impl Shr<$int> for BitBuffer {
+                    type Output = Self;
+
+                    fn shr(self, rhs: $int) -> Self::Output {
+                        Self(self.0 >> rhs)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_shifts! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+    // forwards `Shr` as well
+    forward_ref_shift! {
+        impl Shl for BitBuffer
+    }
+
+    #[test]
+    fn shl() {
+        let b = BitBuffer(0b1011);
+
+        assert_eq!(b << 3u32, b << &3u32);
+        assert_eq!(b << 3u32, &b << 3u32);
+        assert_eq!(b << 3u32, &b << &3u32);
+
+        assert_eq!(b << 3usize, b << &3usize);
+        assert_eq!(b << 3usize, &b << 3usize);
+        assert_eq!(b << 3usize, &b << &3usize);
+
+        assert_eq!(&b << &3u8, &b << &3i128);
+    }
+
+    #[test]
+    fn shr() {
+        let b = BitBuffer(0b1011);
+
+        assert_eq!(b >> 2u32, b >> &2u32);
+        assert_eq!(b >> 2u32, &b >> 2u32);
+        assert_eq!(b >> 2u32, &b >> &2u32);
+
+        assert_eq!(b >> 2usize, b >> &2usize);
+        assert_eq!(b >> 2usize, &b >> 2usize);
+        assert_eq!(b >> 2usize, &b >> &2usize);
+
+        assert_eq!(&b >> &2u16, &b >> &2isize);
+    }
+
+    mod amounts {
+        use forward_ref_generic::forward_ref_shift;
+        use std::ops::Shl;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Bits<T>(T);
+
+        impl<T> Shl<u32> for Bits<T>
+        where
+            T: Shl<u32, Output = T>,
+        {
+            type Output = Self;
+
+            fn shl(self, rhs: u32) -> Self::Output {
+                Self(self.0 << rhs)
+            }
+        }
+
+        impl<T> Shl<usize> for Bits<T>
+        where
+            T: Shl<usize, Output = T>,
+        {
+            type Output = Self;
+
+            fn shl(self, rhs: usize) -> Self::Output {
+                Self(self.0 << rhs)
+            }
+        }
+
+        // only `Shl` is implemented, so its method has to be given to not forward `Shr` as well
+        forward_ref_shift! {
+            [T]
+            impl Shl, shl for Bits<T>, amounts [u32, usize]
+            where T: Copy
+        }
+
+        #[test]
+        fn shl() {
+            let b = Bits(1u8);
+
+            assert_eq!(b << 2u32, &b << &2u32);
+            assert_eq!(b << 2usize, &b << &2usize);
+        }
+    }
+}
+
+mod refs {
+    use super::{forward_ref_binop_refs, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Big<T> {
+        data: [T; 16],
+    }
+
+    impl<T> Add for Big<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut data = self.data;
+            for (val, rhs) in data.iter_mut().zip(rhs.data) {
+                *val = *val + rhs;
+            }
+            Self { data }
+        }
+    }
+
+    forward_ref_binop_refs! {
+        [T]
+        impl Add for Big<T>
+        where T: Copy + Add<Output = T>
+    }
+
+    // these would be conflicting implementations if the macro generated them
+    impl<T> Add<&Big<T>> for Big<T> {
+        type Output = &'static str;
+
+        fn add(self, _rhs: &Big<T>) -> Self::Output {
+            "owned + borrowed"
+        }
+    }
+
+    impl<T> Add<Big<T>> for &Big<T> {
+        type Output = &'static str;
+
+        fn add(self, _rhs: Big<T>) -> Self::Output {
+            "borrowed + owned"
+        }
+    }
+
+    #[test]
+    fn add() {
+        let b1 = Big { data: [1; 16] };
+        let b2 = Big { data: [2; 16] };
+
+        assert_eq!(&b1 + &b2, Big { data: [3; 16] });
+        assert_eq!(b1 + &b2, "owned + borrowed");
+        assert_eq!(&b1 + b2, "borrowed + owned");
+    }
+}
+
+mod multiple_generics {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Pair<A, B>(A, B);
+
+    impl<A, B> Add for Pair<A, B>
+    where
+        A: Copy + Add<Output = A>,
+        B: Copy + Add<Output = B>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0, self.1 + rhs.1)
+        }
+    }
+
+    forward_ref_binop! {
+        [A, B]
+        impl Add for Pair<A, B>
+        where A: Copy + Add<Output = A>, B: Copy + Add<Output = B>
+    }
+
+    #[test]
+    fn add() {
+        let p1 = Pair(1, 2.5);
+        let p2 = Pair(3, 0.5);
+
+        assert_eq!(p1 + p2, Pair(4, 3.0));
+        assert_eq!(p1 + p2, p1 + &p2);
+        assert_eq!(p1 + p2, &p1 + p2);
+        assert_eq!(p1 + p2, &p1 + &p2);
+    }
+}
+
+mod custom_trait {
+    use super::{forward_ref_binop, Add};
+    use std::ops::Mul;
+
+    trait Dot<Rhs> {
+        type Output;
+
+        fn dot(self, rhs: Rhs) -> Self::Output;
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Dot<Vector<T>> for Vector<T>
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        type Output = T;
+
+        fn dot(self, rhs: Vector<T>) -> Self::Output {
+            self.x * rhs.x + self.y * rhs.y
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Dot, dot for Vector<T>
+        where T: Copy + Add<Output = T> + Mul<Output = T>
+    }
+
+    #[test]
+    fn dot() {
+        let v1 = Vector { x: 1, y: 2 };
+        let v2 = Vector { x: 3, y: 4 };
+
+        assert_eq!(v1.dot(v2), 11);
+        assert_eq!(v1.dot(v2), v1.dot(&v2));
+        assert_eq!(v1.dot(v2), (&v1).dot(v2));
+        assert_eq!(v1.dot(v2), (&v1).dot(&v2));
+    }
+}
+
+mod const_generic_lhs_different_rhs {
+    use super::forward_ref_binop;
+    use std::ops::Shl;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Reg<const N: usize> {
+        bits: [bool; N],
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Shift(usize);
+
+    impl<const N: usize> Shl<Shift> for Reg<N> {
+        type Output = Self;
+
+        fn shl(self, rhs: Shift) -> Self::Output {
+            let mut bits = [false; N];
+            for (bit, shifted) in bits.iter_mut().zip(self.bits.into_iter().skip(rhs.0)) {
+                *bit = shifted;
+            }
+            Self { bits }
+        }
+    }
+
+    forward_ref_binop! {
+        [const N: usize]
+        impl Shl for Reg<N>, Shift
+        where Shift: Copy
+    }
+
+    #[test]
+    fn shl() {
+        let r = Reg {
+            bits: [false, true, true, false],
+        };
+        let s = Shift(1);
+
+        assert_eq!(r << s, Reg { bits: [true, true, false, false] });
+        assert_eq!(r << s, r << &s);
+        assert_eq!(r << s, &r << s);
+        assert_eq!(r << s, &r << &s);
+    }
+}
+
+mod arith {
+    use forward_ref_generic::forward_ref_arith;
+    use std::ops::{Add, Div, Mul, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    // implements `Point<T> op Point<T>` componentwise for every listed operator
+    macro_rules! impl_ops {
+        ($($impl:ident, $meth:ident, $op:tt;)*) => {
+            $(
+                impl<T> $impl for Point<T>
+                where
+                    T: Copy + $impl<Output = T>,
+                {
+                    type Output = Self;
+
+                    fn $meth(self, rhs: Self) -> Self::Output {
+                        Self {
+                            x: self.x $op rhs.x,
+                            y: self.y $op rhs.y,
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_ops! { Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; }
+
+    forward_ref_arith! {
+        [T]
+        impl for Point<T>
+        where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+    }
+
+    #[test]
+    fn forward_ref_arith() {
+        macro_rules! assert_forwarded {
+            ($lhs:expr, $rhs:expr; $($op:tt)*) => {
+                $(
+                    assert_eq!($lhs $op $rhs, $lhs $op &$rhs);
+                    assert_eq!($lhs $op $rhs, &$lhs $op $rhs);
+                    assert_eq!($lhs $op $rhs, &$lhs $op &$rhs);
+                )*
+            };
+        }
+
+        let p1 = Point { x: 12, y: 9 };
+        let p2 = Point { x: 3, y: 2 };
+
+        assert_forwarded!(p1, p2; + - * /);
+    }
+
+    mod not_in_scope {
+        use forward_ref_generic::forward_ref_arith;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Int(i32);
+
+        // the traits are only named by their full paths, so the macro has to bring them into scope itself
+        macro_rules! impl_ops {
+            ($($impl:ident, $meth:ident, $op:tt;)*) => {
+                $(
+                    impl std::ops::$impl for Int {
+                        type Output = Self;
+
+                        fn $meth(self, rhs: Self) -> Self::Output {
+                            Self(self.0 $op rhs.0)
+                        }
+                    }
+                )*
+            };
+        }
+
+        impl_ops! { Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; }
+
+        forward_ref_arith! {
+            impl for Int
+        }
+
+        #[test]
+        fn forward_ref_arith() {
+            assert_eq!(&Int(6) + &Int(3), Int(9));
+            assert_eq!(&Int(6) - &Int(3), Int(3));
+            assert_eq!(&Int(6) * &Int(3), Int(18));
+            assert_eq!(&Int(6) / &Int(3), Int(2));
+        }
+    }
+}
+
+mod lifetime_bounds {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Debug, PartialEq)]
+    struct View<'a, T>(&'a T);
+
+    impl<'a, T> Clone for View<'a, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<'a, T> Copy for View<'a, T> {}
+
+    impl<'a, T> Add for View<'a, T>
+    where
+        T: 'a,
+        T: Copy + Add<Output = T>,
+    {
+        type Output = T;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            *self.0 + *rhs.0
+        }
+    }
+
+    forward_ref_binop! {
+        ['a, T]
+        impl Add for View<'a, T>
+        where T: 'a, T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let (x, y) = (1, 2);
+        let v1 = View(&x);
+        let v2 = View(&y);
+
+        assert_eq!(v1 + v2, 3);
+        assert_eq!(v1 + v2, v1 + &v2);
+        assert_eq!(v1 + v2, &v1 + v2);
+        assert_eq!(v1 + v2, &v1 + &v2);
+    }
+}
+
+mod default_generics {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Grid<T = f64, const N: usize = 3> {
+        cells: [T; N],
+    }
+
+    impl<T, const N: usize> Add for Grid<T, N>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut cells = self.cells;
+            for (cell, rhs) in cells.iter_mut().zip(rhs.cells) {
+                *cell = *cell + rhs;
+            }
+            Self { cells }
+        }
+    }
+
+    // defaults are not allowed on implementations, so they are left out
+    forward_ref_binop! {
+        [T, const N: usize]
+        impl Add for Grid<T, N>
+        where T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let g1: Grid = Grid { cells: [1.0, 2.0, 3.0] };
+        let g2: Grid = Grid { cells: [0.5; 3] };
+
+        assert_eq!(g1 + g2, Grid { cells: [1.5, 2.5, 3.5] });
+        assert_eq!(g1 + g2, g1 + &g2);
+        assert_eq!(g1 + g2, &g1 + g2);
+        assert_eq!(g1 + g2, &g1 + &g2);
+    }
+}
+
+mod generic_enum {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Val<T> {
+        A(T),
+        B(T),
+    }
+
+    impl<T> Add for Val<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            match (self, rhs) {
+                (Val::A(x), Val::A(y)) => Val::A(x + y),
+                (Val::A(x) | Val::B(x), Val::A(y) | Val::B(y)) => Val::B(x + y),
+            }
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Val<T>
+        where T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let a = Val::A(1);
+        let b = Val::B(2);
+
+        assert_eq!(a + a, Val::A(2));
+        assert_eq!(a + b, Val::B(3));
+        assert_eq!(a + b, a + &b);
+        assert_eq!(a + b, &a + b);
+        assert_eq!(a + b, &a + &b);
+        assert_eq!(a + a, &a + &a);
+    }
+}
+
+mod depth {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Add for Point<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Point<T>
+        ; depth(2)
+        where T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+
+        assert_eq!(p1 + p2, p1 + &p2);
+        assert_eq!(p1 + p2, &p1 + p2);
+        assert_eq!(p1 + p2, &p1 + &p2);
+        assert_eq!(p1 + p2, (&&p1) + (&&p2));
+    }
+
+    #[test]
+    fn iter() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+        let points = [&p1, &p2];
+
+        let sums: Vec<_> = points.iter().map(|p| p + p).collect();
+        assert_eq!(sums, [p1 + p1, p2 + p2]);
+    }
+}
+
+mod unsized_rhs {
+    use super::{forward_ref_binop_clone, Add};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Text(String);
+
+    impl Add<&str> for Text {
+        type Output = Self;
+
+        fn add(mut self, rhs: &str) -> Self::Output {
+            self.0.push_str(rhs);
+            self
+        }
+    }
+
+    forward_ref_binop_clone! {
+        impl Add for Text, str
+        ; unsized_rhs
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Joined(Vec<i32>);
+
+    impl<U> Add<&U> for Joined
+    where
+        U: ?Sized + AsRef<[i32]>,
+    {
+        type Output = Self;
+
+        fn add(mut self, rhs: &U) -> Self::Output {
+            self.0.extend_from_slice(rhs.as_ref());
+            self
+        }
+    }
+
+    forward_ref_binop_clone! {
+        [U]
+        impl Add for Joined, U
+        ; unsized_rhs
+        where U: ?Sized + AsRef<[i32]>
+    }
+
+    #[test]
+    fn add_str() {
+        let t = Text(String::from("forward"));
+
+        assert_eq!(&t + "_ref", Text(String::from("forward_ref")));
+        assert_eq!(t.clone() + "_ref", &t + "_ref");
+    }
+
+    #[test]
+    fn add_slice() {
+        let j = Joined(vec![1, 2]);
+        let rhs: &[i32] = &[3, 4];
+
+        assert_eq!(&j + rhs, Joined(vec![1, 2, 3, 4]));
+        assert_eq!(j.clone() + rhs, &j + rhs);
+    }
+}
+
+mod local_types {
+    #[test]
+    fn add() {
+        use std::ops::Add;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        impl Add for Point {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    x: self.x + rhs.x,
+                    y: self.y + rhs.y,
+                }
+            }
+        }
+
+        impl Add<i32> for Point {
+            type Output = Self;
+
+            fn add(self, rhs: i32) -> Self::Output {
+                Self {
+                    x: self.x + rhs,
+                    y: self.y + rhs,
+                }
+            }
+        }
+
+        // the macros are deliberately invoked by path without being imported
+        forward_ref_generic::forward_ref_binop! {
+            impl Add for Point
+        }
+
+        forward_ref_generic::commutative_binop! {
+            impl Add for Point, i32
+        }
+
+        forward_ref_generic::forward_ref_commutative_binop! {
+            impl Add for Point, i32
+        }
+
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 5, y: 3 };
+
+        assert_eq!(p1 + p2, &p1 + &p2);
+        assert_eq!(p1 + 1, &1 + &p1);
+    }
+
+    #[test]
+    fn clone() {
+        use std::ops::Add;
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Text(String);
+
+        impl Add for Text {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + &rhs.0)
+            }
+        }
+
+        forward_ref_generic::forward_ref_binop_clone! {
+            impl Add for Text
+        }
+
+        let t1 = Text(String::from("a"));
+        let t2 = Text(String::from("b"));
+
+        assert_eq!(&t1 + &t2, Text(String::from("ab")));
+    }
+}
+
+mod scalar_binop {
+    use forward_ref_generic::impl_scalar_binop;
+    use std::ops::{Div, Mul, Rem, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T, const N: usize>([T; N]);
+
+    impl_scalar_binop! {
+        [T, const N: usize]
+        impl Mul for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x * s)) }
+        where T: Copy + Mul<Output = T>
+    }
+
+    impl_scalar_binop! {
+        [T, const N: usize]
+        impl Sub for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x - s)) }
+        where T: Copy + Sub<Output = T>
+    }
+
+    impl_scalar_binop! {
+        [T, const N: usize]
+        impl Div for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x / s)) }
+        where T: Copy + Div<Output = T>
+    }
+
+    impl_scalar_binop! {
+        [T, const N: usize]
+        impl Rem for Vector<T, N>, T { |v, s| Vector(v.0.map(|x| x % s)) }
+        where T: Copy + Rem<Output = T>
+    }
+
+    #[test]
+    fn mul() {
+        let v = Vector([1, 2, 3]);
+
+        assert_eq!(v * 2, Vector([2, 4, 6]));
+        assert_eq!(v * 2, &v * 2);
+        assert_eq!(v * 2, v * &2);
+        assert_eq!(v * 2, &v * &2);
+    }
+
+    #[test]
+    fn sub() {
+        let v = Vector([1, 2, 3]);
+
+        assert_eq!(v - 2, Vector([-1, 0, 1]));
+        assert_eq!(v - 2, &v - 2);
+        assert_eq!(v - 2, v - &2);
+        assert_eq!(v - 2, &v - &2);
+    }
+
+    #[test]
+    fn div() {
+        let v = Vector([2, 4, 7]);
+
+        assert_eq!(v / 2, Vector([1, 2, 3]));
+        assert_eq!(v / 2, &v / 2);
+        assert_eq!(v / 2, v / &2);
+        assert_eq!(v / 2, &v / &2);
+    }
+
+    #[test]
+    fn rem() {
+        let v = Vector([2, 4, 7]);
+
+        assert_eq!(v % 3, Vector([2, 1, 1]));
+        assert_eq!(v % 3, &v % 3);
+        assert_eq!(v % 3, v % &3);
+        assert_eq!(v % 3, &v % &3);
+    }
+}
+
+mod const_generic_bounds {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Buffer<T, const N: usize>([T; N]);
+
+    struct Len<const N: usize>;
+
+    trait Supported {}
+
+    impl<const N: usize> Supported for Len<N> {}
+
+    impl<T, const N: usize> Add for Buffer<T, N>
+    where
+        [T; N]: Copy,
+        Len<{ N }>: Supported,
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut buf = self.0;
+            for (val, rhs) in buf.iter_mut().zip(rhs.0) {
+                *val = *val + rhs;
+            }
+            Self(buf)
+        }
+    }
+
+    // the bracket and brace tokens in the bounds are passed through as they are
+    forward_ref_binop! {
+        [T, const N: usize]
+        impl Add for Buffer<T, N>
+        where [T; N]: Copy, Len<{ N }>: Supported, T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let b1 = Buffer([1, 2]);
+        let b2 = Buffer([3, 4]);
+
+        assert_eq!(b1 + b2, Buffer([4, 6]));
+        assert_eq!(b1 + b2, b1 + &b2);
+        assert_eq!(b1 + b2, &b1 + b2);
+        assert_eq!(b1 + b2, &b1 + &b2);
+    }
+}
+
+mod lifetime_type_const_generics {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Debug, PartialEq)]
+    struct Frame<'a, T, const N: usize> {
+        buf: &'a [T; N],
+    }
+
+    impl<'a, T, const N: usize> Clone for Frame<'a, T, N> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<'a, T, const N: usize> Copy for Frame<'a, T, N> {}
+
+    impl<'a, T, const N: usize> Add for Frame<'a, T, N>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = [T; N];
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut buf = *self.buf;
+            for (val, rhs) in buf.iter_mut().zip(rhs.buf) {
+                *val = *val + *rhs;
+            }
+            buf
+        }
+    }
+
+    forward_ref_binop! {
+        ['a, T, const N: usize]
+        impl Add for Frame<'a, T, N>
+        where T: Copy + Add<Output = T>
+    }
+
+    #[test]
+    fn add() {
+        let (a, b) = ([1, 2, 3], [4, 5, 6]);
+        let f1 = Frame { buf: &a };
+        let f2 = Frame { buf: &b };
+
+        assert_eq!(f1 + f2, [5, 7, 9]);
+        assert_eq!(f1 + f2, f1 + &f2);
+        assert_eq!(f1 + f2, &f1 + f2);
+        assert_eq!(f1 + f2, &f1 + &f2);
+    }
+}
+
+mod non_copy_output {
+    use super::forward_ref_binop;
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Matrix([[i32; 2]; 2]);
+
+    // heap-backed and therefore not `Copy`
+    #[derive(Clone, Debug, PartialEq)]
+    struct DynMatrix(Vec<Vec<i32>>);
+
+    impl Mul for Matrix {
+        type Output = DynMatrix;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut result = vec![vec![0; 2]; 2];
+            for (i, row) in result.iter_mut().enumerate() {
+                for (j, val) in row.iter_mut().enumerate() {
+                    for k in 0..2 {
+                        *val += self.0[i][k] * rhs.0[k][j];
+                    }
+                }
+            }
+            DynMatrix(result)
+        }
+    }
+
+    forward_ref_binop! {
+        impl Mul for Matrix
+    }
+
+    #[test]
+    fn mul() {
+        let m1 = Matrix([[1, 2], [3, 4]]);
+        let m2 = Matrix([[0, 1], [1, 0]]);
+
+        assert_eq!(m1 * m2, DynMatrix(vec![vec![2, 1], vec![4, 3]]));
+        assert_eq!(m1 * m2, m1 * &m2);
+        assert_eq!(m1 * m2, &m1 * m2);
+        assert_eq!(m1 * m2, &m1 * &m2);
+    }
+}
+
+mod different_type_params {
+    use super::{forward_ref_binop, Add};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T, U> Add<Point<U>> for Point<T>
+    where
+        T: Copy + Add<U, Output = T>,
+        U: Copy,
+    {
+        type Output = Point<T>;
+
+        fn add(self, rhs: Point<U>) -> Self::Output {
+            Point {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    forward_ref_binop! {
+        [T, U]
+        impl Add for Point<T>, Point<U>
+        where T: Copy + Add<U, Output = T>, U: Copy
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Meters(f64);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Centimeters(f64);
+
+    impl Add<Centimeters> for Meters {
+        type Output = Self;
+
+        fn add(self, rhs: Centimeters) -> Self::Output {
+            Self(self.0 + rhs.0 / 100.0)
+        }
+    }
+
+    #[test]
+    fn add() {
+        let p1 = Point { x: Meters(1.0), y: Meters(2.0) };
+        let p2 = Point { x: Centimeters(50.0), y: Centimeters(25.0) };
+
+        assert_eq!(p1 + p2, Point { x: Meters(1.5), y: Meters(2.25) });
+        assert_eq!(p1 + p2, p1 + &p2);
+        assert_eq!(p1 + p2, &p1 + p2);
+        assert_eq!(p1 + p2, &p1 + &p2);
+    }
+}
+
+mod type_alias {
+    use super::{commutative_binop, forward_ref_binop, forward_ref_commutative_binop, Add};
+    use std::ops::Mul;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T, const N: usize>([T; N]);
+
+    type Vec3 = Vector<f64, 3>;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Scale(f64);
+
+    impl Add for Vec3 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let [x, y, z] = self.0;
+            let [a, b, c] = rhs.0;
+            Vector([x + a, y + b, z + c])
+        }
+    }
+
+    impl Mul<Scale> for Vec3 {
+        type Output = Self;
+
+        fn mul(self, rhs: Scale) -> Self::Output {
+            Vector(self.0.map(|x| x * rhs.0))
+        }
+    }
+
+    forward_ref_binop! {
+        impl Add for Vec3
+    }
+
+    commutative_binop! {
+        impl Mul for Vec3, Scale
+    }
+
+    forward_ref_commutative_binop! {
+        impl Mul for Vec3, Scale
+    }
+
+    #[test]
+    fn add() {
+        let v1: Vec3 = Vector([1.0, 2.0, 3.0]);
+        let v2: Vec3 = Vector([0.5, 0.5, 0.5]);
+
+        assert_eq!(v1 + v2, Vector([1.5, 2.5, 3.5]));
+        assert_eq!(v1 + v2, v1 + &v2);
+        assert_eq!(v1 + v2, &v1 + v2);
+        assert_eq!(v1 + v2, &v1 + &v2);
+    }
+
+    #[test]
+    fn mul_commutative() {
+        let v: Vec3 = Vector([1.0, 2.0, 3.0]);
+        let s = Scale(2.0);
+
+        assert_eq!(v * s, Vector([2.0, 4.0, 6.0]));
+        assert_eq!(v * s, s * v);
+        assert_eq!(v * s, &v * &s);
+        assert_eq!(v * s, &s * &v);
+    }
+}
+
+mod commutative_clone {
+    use super::Add;
+    use forward_ref_generic::commutative_binop_clone;
+
+    // heap-backed and therefore only `Clone`
+    #[derive(Clone, Debug, PartialEq)]
+    struct Words(Vec<String>);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Word(String);
+
+    impl Add<Word> for Words {
+        type Output = Vec<String>;
+
+        // sorted, so the order of the operands does not matter
+        fn add(self, rhs: Word) -> Self::Output {
+            let mut words = self.0;
+            words.push(rhs.0);
+            words.sort();
+            words
+        }
+    }
+
+    commutative_binop_clone! {
+        impl Add for Words, Word
+    }
+
+    #[test]
+    fn add() {
+        let words = Words(vec!["b".to_string(), "c".to_string()]);
+        let word = Word("a".to_string());
+        let expected = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        assert_eq!(words.clone() + word.clone(), expected);
+        assert_eq!(word.clone() + words.clone(), expected);
+
+        assert_eq!(&words + word.clone(), expected);
+        assert_eq!(words.clone() + &word, expected);
+        assert_eq!(&words + &word, expected);
+
+        assert_eq!(&word + words.clone(), expected);
+        assert_eq!(word.clone() + &words, expected);
+        assert_eq!(&word + &words, expected);
+    }
+}
@@ -0,0 +1,169 @@
+#![allow(clippy::op_ref)]
+
+use forward_ref_generic::forward_ref_ord;
+use std::cmp::Ordering;
+
+mod same_type {
+    use super::{forward_ref_ord, Ordering};
+
+    // ordered by length first, not `Copy`
+    #[derive(Debug, PartialEq, Eq)]
+    struct Word(String);
+
+    impl PartialOrd for Word {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Word {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0
+                .len()
+                .cmp(&other.0.len())
+                .then_with(|| self.0.cmp(&other.0))
+        }
+    }
+
+    forward_ref_ord! {
+        impl PartialOrd for Word
+    }
+
+    #[test]
+    fn cmp() {
+        let w1 = Word(String::from("zz"));
+        let w2 = Word(String::from("aaa"));
+
+        assert!(w1 < w2);
+        assert!(w1 < &w2);
+        assert!(&w1 < w2);
+        assert!(&w1 < &w2);
+
+        assert!(w1 != &w2);
+        assert!(&w1 != w2);
+        assert!(w1 == &w1);
+        assert!(&w1 == w1);
+    }
+}
+
+mod different_rhs {
+    use super::{forward_ref_ord, Ordering};
+
+    #[derive(Debug, PartialEq)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+
+    // compares only the major version
+    impl PartialEq<u32> for Version {
+        fn eq(&self, other: &u32) -> bool {
+            self.major == *other
+        }
+    }
+
+    impl PartialOrd<u32> for Version {
+        fn partial_cmp(&self, other: &u32) -> Option<Ordering> {
+            self.major.partial_cmp(other)
+        }
+    }
+
+    forward_ref_ord! {
+        impl PartialOrd for Version, u32
+    }
+
+    #[test]
+    fn cmp() {
+        let v = Version { major: 2, minor: 5 };
+
+        assert_eq!(v.minor, 5);
+        assert_eq!(v.partial_cmp(&&3), Some(Ordering::Less));
+        assert_eq!(PartialOrd::partial_cmp(&&v, &3), Some(Ordering::Less));
+
+        assert!(v < &3);
+        assert!(&v < 3);
+        assert!(v >= &2);
+        assert!(&v >= 2);
+        assert!(v == &2);
+        assert!(&v == 2);
+        assert!(v != &3);
+        assert!(&v != 3);
+    }
+}
+
+mod generic {
+    use super::forward_ref_ord;
+
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    forward_ref_ord! {
+        [T]
+        impl PartialOrd for Point<T>
+        where T: PartialOrd
+    }
+
+    #[test]
+    fn cmp() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = Point { x: 1, y: 3 };
+
+        assert!(p1 < &p2);
+        assert!(&p1 < p2);
+        assert!(p1 != &p2);
+        assert!(&p1 != p2);
+    }
+}
+
+mod local_types {
+    #[test]
+    fn cmp() {
+        #[derive(Debug, PartialEq, PartialOrd)]
+        struct Word(String);
+
+        // the macro is deliberately invoked by path without being imported
+        forward_ref_generic::forward_ref_ord! {
+            impl PartialOrd for Word
+        }
+
+        let w1 = Word(String::from("a"));
+        let w2 = Word(String::from("b"));
+
+        assert!(w1 < &w2);
+        assert!(&w1 < w2);
+    }
+}
+
+mod commutative_partial_eq {
+    use forward_ref_generic::commutative_partial_eq;
+
+    #[derive(Clone, Copy, Debug)]
+    struct Feet(f64);
+
+    #[derive(Clone, Copy, Debug)]
+    struct Meters(f64);
+
+    impl PartialEq<Meters> for Feet {
+        fn eq(&self, other: &Meters) -> bool {
+            (self.0 * 0.3048 - other.0).abs() < 1e-9
+        }
+    }
+
+    commutative_partial_eq! {
+        impl PartialEq for Feet, Meters
+    }
+
+    #[test]
+    fn eq() {
+        let f = Feet(10.0);
+        let m = Meters(3.048);
+
+        assert!(f == m);
+        assert!(m == f);
+        assert_eq!(f == Meters(1.0), Meters(1.0) == f);
+        assert!(m != Feet(1.0));
+    }
+}
@@ -0,0 +1,72 @@
+use forward_ref_generic::{forward_ref_binop, forward_ref_op_assign, forward_ref_unop};
+use std::ops::{Add, AddAssign, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Int(i32);
+
+impl const Add for Int {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl const Neg for Int {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl const AddAssign for Int {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+// not const, to test that only invocations with `impl const` generate const implementations
+impl Sub for Int {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+forward_ref_binop! {
+    impl const Add for Int
+}
+
+forward_ref_binop! {
+    impl Sub for Int
+}
+
+forward_ref_unop! {
+    impl const Neg for Int
+}
+
+forward_ref_op_assign! {
+    impl const AddAssign for Int
+}
+
+const SUM: Int = &Int(1) + &Int(2);
+const NEG: Int = -&Int(3);
+const ASSIGNED: Int = {
+    let mut i = Int(1);
+    i += &Int(4);
+    i
+};
+
+#[test]
+fn const_context() {
+    assert_eq!(SUM, Int(3));
+    assert_eq!(NEG, Int(-3));
+    assert_eq!(ASSIGNED, Int(5));
+}
+
+#[test]
+fn non_const() {
+    assert_eq!(&Int(3) - &Int(1), Int(2));
+}
@@ -0,0 +1,7 @@
+//! Requires a nightly compiler, run with `RUSTFLAGS="--cfg nightly" cargo +nightly test --test const`.
+#![cfg_attr(nightly, feature(const_trait_impl, const_ops))]
+
+// `impl const` does not even parse on stable,
+// so the tests are in a module that is only loaded on nightly
+#[cfg(nightly)]
+mod impls;
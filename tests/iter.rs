@@ -0,0 +1,78 @@
+use std::ops::Add;
+
+mod sum_from_add {
+    use super::Add;
+    use forward_ref_generic::sum_from_add;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Add for Point<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    sum_from_add! {
+        [T]
+        impl Sum for Point<T>; zero = Point { x: T::default(), y: T::default() }
+        where T: Copy + Default + Add<Output = T>
+    }
+
+    #[test]
+    fn sum() {
+        let points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }, Point { x: 5, y: 6 }];
+
+        assert_eq!(points.into_iter().sum::<Point<i32>>(), Point { x: 9, y: 12 });
+        assert_eq!(points.iter().sum::<Point<i32>>(), Point { x: 9, y: 12 });
+        assert_eq!([].iter().sum::<Point<i32>>(), Point { x: 0, y: 0 });
+    }
+}
+
+mod product_from_mul {
+    use forward_ref_generic::product_from_mul;
+    use std::ops::{Add, Mul};
+
+    // 2x2 matrix, whose multiplication is not commutative
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct M<T>([[T; 2]; 2]);
+
+    impl<T> Mul for M<T>
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            let [[a, b], [c, d]] = self.0;
+            let [[e, f], [g, h]] = rhs.0;
+            Self([[a * e + b * g, a * f + b * h], [c * e + d * g, c * f + d * h]])
+        }
+    }
+
+    product_from_mul! {
+        [T]
+        impl Product for M<T>; one = M([[T::from(1), T::from(0)], [T::from(0), T::from(1)]])
+        where T: Copy + From<u8> + Add<Output = T> + Mul<Output = T>
+    }
+
+    #[test]
+    fn product() {
+        let matrices = [M([[1, 2], [3, 4]]), M([[0, 1], [1, 0]]), M([[2, 0], [0, 3]])];
+
+        assert_eq!(matrices.into_iter().product::<M<i32>>(), M([[4, 3], [8, 9]]));
+        assert_eq!(matrices.iter().product::<M<i32>>(), M([[4, 3], [8, 9]]));
+        assert_eq!([].iter().product::<M<i32>>(), M([[1, 0], [0, 1]]));
+    }
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::op_ref)]
+
+use std::ops::Add;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Int(i32);
+
+impl Add for Int {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+mod root {
+    use super::{Add, Int};
+    use forward_ref_generic::{forward_ref_binop, forward_ref_ord};
+
+    forward_ref_binop! {
+        impl Add for Int
+    }
+
+    forward_ref_ord! {
+        impl PartialOrd for Int
+    }
+}
+
+mod modules {
+    use forward_ref_generic::cmp::forward_ref_ord;
+    use forward_ref_generic::ops::forward_ref_binop_refs;
+    use std::ops::Add;
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Int(i32);
+
+    impl Add for Int {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    forward_ref_binop_refs! {
+        impl Add for Int
+    }
+
+    forward_ref_ord! {
+        impl PartialOrd for Int
+    }
+
+    #[test]
+    fn modules() {
+        assert_eq!(&Int(1) + &Int(2), Int(3));
+        assert!(Int(1) < &Int(2));
+    }
+}
+
+#[test]
+fn root() {
+    assert_eq!(&Int(1) + &Int(2), Int(3));
+    assert!(Int(1) < &Int(2));
+}
+
+mod prelude {
+    use forward_ref_generic::prelude::*;
+    use std::ops::{Add, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Int(i32);
+
+    impl Add for Int {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    impl Neg for Int {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(-self.0)
+        }
+    }
+
+    forward_ref_binop! {
+        impl Add for Int
+    }
+
+    forward_ref_unop! {
+        impl Neg for Int
+    }
+
+    forward_ref_ord! {
+        impl PartialOrd for Int
+    }
+
+    sum_from_add! {
+        impl Sum for Int; zero = Int(0)
+    }
+
+    #[test]
+    fn prelude() {
+        assert_eq!(&Int(1) + &Int(2), Int(3));
+        assert_eq!(-&Int(1), Int(-1));
+        assert!(Int(1) < &Int(2));
+        assert_eq!([Int(1), Int(2)].iter().sum::<Int>(), Int(3));
+    }
+}
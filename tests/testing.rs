@@ -0,0 +1,120 @@
+//! Run with `cargo test --features testing --test testing`.
+#![cfg(feature = "testing")]
+
+use forward_ref_generic::{assert_forward_ref_binop, forward_ref_binop};
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+// deliberately wrong for `&Point * &Point`
+impl Mul for Point {
+    type Output = i32;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+impl Mul<&Point> for Point {
+    type Output = i32;
+
+    fn mul(self, rhs: &Point) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl Mul<Point> for &Point {
+    type Output = i32;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl Mul<&Point> for &Point {
+    type Output = i32;
+
+    fn mul(self, _rhs: &Point) -> Self::Output {
+        0
+    }
+}
+
+forward_ref_binop! {
+    impl Add for Point
+}
+
+forward_ref_binop! {
+    impl Sub for Point
+}
+
+#[test]
+fn all_variants() {
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: 5, y: 3 };
+
+    assert_forward_ref_binop!(p1 + p2 => Point { x: 6, y: 5 });
+    assert_forward_ref_binop!(p1 - (Point { x: 1, y: 1 }) => Point { x: 0, y: 1 });
+    assert_forward_ref_binop!(2 * 3 => 6);
+}
+
+#[test]
+#[should_panic(expected = "&p1 * &p2")]
+fn wrong_variant() {
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: 5, y: 3 };
+
+    assert_forward_ref_binop!(p1 * p2 => 11);
+}
+
+mod prelude {
+    use forward_ref_generic::prelude::*;
+    use std::ops::Add;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    impl Add for Int {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    forward_ref_binop! {
+        impl Add for Int
+    }
+
+    #[test]
+    fn prelude() {
+        let a = Int(1);
+
+        assert_forward_ref_binop!(a + (Int(2)) => Int(3));
+    }
+}
@@ -0,0 +1,21 @@
+use forward_ref_generic::forward_ref_unop;
+use std::ops::Neg;
+
+#[derive(Clone, Copy)]
+struct Int(i32);
+
+impl Neg for Int {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+forward_ref_unop! {
+    impl Neg for Int {
+        type Output = Int;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use forward_ref_generic::forward_ref_binop;
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+forward_ref_binop! {
+    impl<T> Add for Point<T>
+    where T: Copy + Add<Output = T>
+}
+
+fn main() {}
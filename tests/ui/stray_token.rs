@@ -0,0 +1,19 @@
+use forward_ref_generic::forward_ref_binop;
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Int(i32);
+
+impl Add for Int {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+forward_ref_binop! {
+    impl Add for Int, Int, Int
+}
+
+fn main() {}
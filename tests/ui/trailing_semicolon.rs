@@ -0,0 +1,17 @@
+use forward_ref_generic::forward_ref_op_assign;
+use std::ops::AddAssign;
+
+#[derive(Clone, Copy)]
+struct Int(i32);
+
+impl AddAssign for Int {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+forward_ref_op_assign! {
+    impl AddAssign for Int;
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+use forward_ref_generic::{
+    derive_shift_assign_from_shift, derive_sub_from_add_neg, forward_ref_binop_clone,
+    impl_commutative_binop,
+};
+use std::ops::{Add, Neg, Shl};
+
+#[derive(Clone, Copy)]
+struct Int(i32);
+
+#[derive(Clone, Copy)]
+struct Scalar(i32);
+
+impl Add for Int {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Neg for Int {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Shl for Int {
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        Self(self.0 << rhs.0)
+    }
+}
+
+forward_ref_binop_clone! {
+    impl Add for Int; unsized
+}
+
+derive_sub_from_add_neg! {
+    impl Sub for Int; forward
+}
+
+derive_shift_assign_from_shift! {
+    impl ShlAssign for Int; forward_refs
+}
+
+impl_commutative_binop! {
+    impl Mul for Int, Scalar { |a, b| -> i32 { a.0 * b.0 } }
+    ; forward_ref_binop
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use forward_ref_generic::forward_ref_binop;
+
+trait Dot<Rhs> {
+    type Output;
+
+    fn dot(self, rhs: Rhs) -> Self::Output;
+}
+
+#[derive(Clone, Copy)]
+struct Vector(i32, i32);
+
+impl Dot<Vector> for Vector {
+    type Output = i32;
+
+    fn dot(self, rhs: Vector) -> Self::Output {
+        self.0 * rhs.0 + self.1 * rhs.1
+    }
+}
+
+forward_ref_binop! {
+    impl Dot for Vector
+}
+
+fn main() {}
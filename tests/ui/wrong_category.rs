@@ -0,0 +1,19 @@
+use forward_ref_generic::forward_ref_op_assign;
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Q(i32);
+
+impl Add for Q {
+    type Output = Q;
+
+    fn add(self, rhs: Q) -> Self::Output {
+        Q(self.0 + rhs.0)
+    }
+}
+
+forward_ref_op_assign! {
+    impl Add for Q
+}
+
+fn main() {}
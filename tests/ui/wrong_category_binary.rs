@@ -0,0 +1,38 @@
+use forward_ref_generic::{
+    forward_ref_binop_clone, forward_ref_binop_refs, forward_ref_commutative_binop,
+};
+use std::ops::{AddAssign, Neg};
+
+#[derive(Clone, Copy)]
+struct Q(i32);
+
+#[derive(Clone, Copy)]
+struct R(i32);
+
+impl Neg for Q {
+    type Output = Q;
+
+    fn neg(self) -> Self::Output {
+        Q(-self.0)
+    }
+}
+
+impl AddAssign for Q {
+    fn add_assign(&mut self, rhs: Q) {
+        self.0 += rhs.0;
+    }
+}
+
+forward_ref_binop_refs! {
+    impl Neg for Q
+}
+
+forward_ref_binop_clone! {
+    impl AddAssign for Q
+}
+
+forward_ref_commutative_binop! {
+    impl Neg for Q, R
+}
+
+fn main() {}
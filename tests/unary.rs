@@ -0,0 +1,467 @@
+use forward_ref_generic::forward_ref_unop;
+use std::ops::{Neg, Not};
+
+mod no_generic {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Neg for Point {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self {
+                x: -self.x,
+                y: -self.y,
+            }
+        }
+    }
+
+    forward_ref_unop! {
+        impl Neg for Point
+    }
+
+    #[test]
+    fn neg() {
+        let p = Point { x: 1, y: -2 };
+
+        assert_eq!(-p, -&p);
+    }
+}
+
+mod multiple_traits {
+    use super::{forward_ref_unop, Neg, Not};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Mask<T>(T);
+
+    impl<T> Neg for Mask<T>
+    where
+        T: Copy + Neg<Output = T> + Not<Output = T>,
+    {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(-self.0)
+        }
+    }
+
+    impl<T> Not for Mask<T>
+    where
+        T: Copy + Neg<Output = T> + Not<Output = T>,
+    {
+        type Output = Self;
+
+        fn not(self) -> Self::Output {
+            Self(!self.0)
+        }
+    }
+
+    forward_ref_unop! {
+        [T]
+        impl { Neg, Not } for Mask<T>
+        where T: Copy + Neg<Output = T> + Not<Output = T>
+    }
+
+    #[test]
+    fn neg() {
+        let m = Mask(0b0101i8);
+
+        assert_eq!(-m, -&m);
+    }
+
+    #[test]
+    fn not() {
+        let m = Mask(0b0101i8);
+
+        assert_eq!(!m, !&m);
+    }
+}
+
+mod const_generics {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Matrix<T, const M: usize, const N: usize> {
+        m: [[T; N]; M],
+    }
+
+    impl<T, const M: usize, const N: usize> Neg for Matrix<T, M, N>
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self {
+                m: self.m.map(|row| row.map(|x| -x)),
+            }
+        }
+    }
+
+    forward_ref_unop! {
+        [T, const M: usize, const N: usize]
+        impl Neg for Matrix<T, M, N>
+        where T: Copy + Neg<Output = T>
+    }
+
+    #[test]
+    fn neg() {
+        let m = Matrix {
+            m: [[1, -2, 2], [2, 1, -2]],
+        };
+
+        assert_eq!(-m, Matrix { m: [[-1, 2, -2], [-2, -1, 2]] });
+        assert_eq!(-m, -&m);
+    }
+}
+
+mod cfg {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    impl Neg for Int {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(-self.0)
+        }
+    }
+
+    // this would be a conflicting implementation if it wasn't disabled
+    forward_ref_unop! {
+        #[cfg(any())]
+        impl Neg for Int
+    }
+
+    forward_ref_unop! {
+        #[cfg(all())]
+        impl Neg for Int
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-Int(3), -&Int(3));
+    }
+}
+
+mod local_types {
+    #[test]
+    fn neg_not() {
+        use std::ops::{Neg, Not};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Int(i32);
+
+        impl Neg for Int {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl Not for Int {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self(!self.0)
+            }
+        }
+
+        // the macro is deliberately invoked by path without being imported
+        forward_ref_generic::forward_ref_unop! {
+            impl { Neg, Not } for Int
+        }
+
+        assert_eq!(-Int(3), -&Int(3));
+        assert_eq!(!Int(3), !&Int(3));
+    }
+}
+
+mod different_output {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Wrap<T>(T);
+
+    impl<T> Neg for Wrap<T>
+    where
+        T: Neg,
+    {
+        type Output = Wrap<T::Output>;
+
+        fn neg(self) -> Self::Output {
+            Wrap(-self.0)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Unsigned(u8);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Signed(i16);
+
+    impl Neg for Unsigned {
+        type Output = Signed;
+
+        fn neg(self) -> Self::Output {
+            Signed(-i16::from(self.0))
+        }
+    }
+
+    forward_ref_unop! {
+        [T]
+        impl Neg for Wrap<T>
+        where T: Copy + Neg
+    }
+
+    #[test]
+    fn neg() {
+        let w = Wrap(Unsigned(3));
+
+        assert_eq!(-w, Wrap(Signed(-3)));
+        assert_eq!(-w, -&w);
+    }
+}
+
+mod lifetime {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Slice<'a, T>(&'a [T]);
+
+    impl<'a, T> Neg for Slice<'a, T>
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        type Output = Vec<T>;
+
+        fn neg(self) -> Self::Output {
+            self.0.iter().map(|&x| -x).collect()
+        }
+    }
+
+    forward_ref_unop! {
+        ['a, T]
+        impl Neg for Slice<'a, T>
+        where T: Copy + Neg<Output = T>
+    }
+
+    #[test]
+    fn neg() {
+        let values = [1, -2, 3];
+        let s = Slice(&values);
+
+        assert_eq!(-s, vec![-1, 2, -3]);
+        assert_eq!(-s, -&s);
+    }
+}
+
+mod method_attributes {
+    use super::{forward_ref_unop, Neg, Not};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Int(i32);
+
+    impl Neg for Int {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(-self.0)
+        }
+    }
+
+    impl Not for Int {
+        type Output = Self;
+
+        fn not(self) -> Self::Output {
+            Self(!self.0)
+        }
+    }
+
+    forward_ref_unop! {
+        #[cfg_attr(all(), inline(always))]
+        impl Neg for Int
+    }
+
+    // the attribute does not exist, so this only compiles if it is never applied
+    forward_ref_unop! {
+        #[cfg_attr(any(), no_such_attribute)]
+        impl Not for Int
+    }
+
+    #[test]
+    fn cfg_attr() {
+        assert_eq!(-Int(3), -&Int(3));
+        assert_eq!(!Int(3), !&Int(3));
+    }
+}
+
+mod impl_unop {
+    use super::Neg;
+    use forward_ref_generic::impl_unop;
+
+    trait Conj {
+        type Output;
+
+        fn conj(self) -> Self::Output;
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Complex<T> {
+        re: T,
+        im: T,
+    }
+
+    impl_unop! {
+        [T]
+        impl Conj, conj -> Complex<T> for Complex<T> { |c| Complex { re: c.re, im: -c.im } }
+        where T: Copy + Neg<Output = T>
+    }
+
+    impl_unop! {
+        [T]
+        impl Neg for Complex<T> { |Complex { re, im }| Complex { re: -re, im: -im } }
+        where T: Copy + Neg<Output = T>
+    }
+
+    #[test]
+    fn conj() {
+        let c = Complex { re: 1, im: 2 };
+
+        assert_eq!(c.conj(), Complex { re: 1, im: -2 });
+        assert_eq!(c.conj(), (&c).conj());
+    }
+
+    #[test]
+    fn neg() {
+        let c = Complex { re: 1, im: 2 };
+
+        assert_eq!(-c, Complex { re: -1, im: -2 });
+        assert_eq!(-c, -&c);
+    }
+}
+
+mod with_binop {
+    // the references are the point of this test
+    #![allow(clippy::op_ref)]
+
+    use super::{forward_ref_unop, Neg};
+    use forward_ref_generic::forward_ref_binop;
+    use std::ops::{Add, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T> Neg for Point<T>
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self {
+                x: -self.x,
+                y: -self.y,
+            }
+        }
+    }
+
+    impl<T> Add for Point<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    impl<T> Sub for Point<T>
+    where
+        T: Copy + Sub<Output = T>,
+    {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x - rhs.x,
+                y: self.y - rhs.y,
+            }
+        }
+    }
+
+    forward_ref_unop! {
+        [T]
+        impl Neg for Point<T>
+        where T: Copy + Neg<Output = T>
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Add for Point<T>
+        where T: Copy + Add<Output = T>
+    }
+
+    forward_ref_binop! {
+        [T]
+        impl Sub for Point<T>
+        where T: Copy + Sub<Output = T>
+    }
+
+    #[test]
+    fn chained() {
+        let a = Point { x: 1, y: 2 };
+        let b = Point { x: 5, y: 3 };
+
+        assert_eq!(&a + -&b, Point { x: -4, y: -1 });
+        assert_eq!(&a + -&b, a - b);
+        assert_eq!(-&a + &b, b - a);
+        assert_eq!(-&a - -&b, b - a);
+        assert_eq!(-(&a + &b), -a + -b);
+        assert_eq!(-(-&a), a);
+    }
+}
+
+mod type_alias {
+    use super::{forward_ref_unop, Neg};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector<T, const N: usize>([T; N]);
+
+    type Vec3 = Vector<f64, 3>;
+
+    impl Neg for Vec3 {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Vector(self.0.map(|x| -x))
+        }
+    }
+
+    forward_ref_unop! {
+        impl Neg for Vec3
+    }
+
+    #[test]
+    fn neg() {
+        let v: Vec3 = Vector([1.0, -2.0, 3.0]);
+
+        assert_eq!(-v, Vector([-1.0, 2.0, -3.0]));
+        assert_eq!(-v, -&v);
+    }
+}